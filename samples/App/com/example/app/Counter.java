@@ -0,0 +1,9 @@
+package com.example.app;
+
+class Counter {
+    private static int count;
+
+    static int next() {
+        return ++count;
+    }
+}
@@ -0,0 +1,12 @@
+package com.example.app;
+
+import com.example.greeting.Greeter;
+
+public class Main {
+    public static void main(String[] args) {
+        Greeter greeter = new Greeter("Hello");
+        greeter.greet("JAR");
+        System.out.println(Counter.next());
+        System.out.println(Counter.next());
+    }
+}
@@ -0,0 +1,16 @@
+public class Args {
+    public static void main(String[] args) {
+        System.out.println(args.length);
+        for (int i = 0; i < args.length; i++) {
+            System.out.println(args[i]);
+        }
+        for (String arg : args) {
+            System.out.println(arg == "first");
+        }
+        try {
+            System.out.println(args[args.length]);
+        } catch (ArrayIndexOutOfBoundsException e) {
+            System.out.println(e.getMessage());
+        }
+    }
+}
@@ -0,0 +1,78 @@
+class Arithmetic {
+    public static void main(String[] args) {
+        {
+            int i = 2147483647;
+            System.out.println(i + 1);
+            System.out.println(i * 3);
+            System.out.println(-i - 2);
+            System.out.println(i << 33);
+        }
+        {
+            int n = -17;
+            System.out.println(n >> 2);
+            System.out.println(n >>> 28);
+            System.out.println(n & 0xff);
+            System.out.println(n | 0x100);
+            System.out.println(n ^ -1);
+            System.out.println((byte) (n * 20));
+            System.out.println((short) (n * 5000));
+            System.out.println((char) (n + 82));
+            System.out.println(n < 0);
+        }
+        {
+            long l = 9223372036854775807L;
+            System.out.println(l + 1);
+            System.out.println(l * 2);
+            System.out.println(-l);
+            System.out.println(l >> 60);
+            System.out.println((l + 1) >>> 1);
+            System.out.println(l << 65);
+            System.out.println(l & 0xffff);
+            System.out.println(l ^ 0x5555L);
+            System.out.println((int) l);
+            System.out.println((float) l);
+            System.out.println((double) l);
+            System.out.println(l > 0);
+        }
+        {
+            float f = 1.1f;
+            System.out.println(f * 3);
+            System.out.println(f / 0);
+            System.out.println(-f / 0);
+            System.out.println(f % 0.5f);
+            System.out.println(f - f == 0);
+            System.out.println((double) f);
+            System.out.println((int) (f * 1e10f));
+            System.out.println((long) (f * -1e30f));
+        }
+        {
+            double d = 0.1;
+            double nan = d * 0 / 0;
+            System.out.println(d + 0.2);
+            System.out.println(d * 3);
+            System.out.println(d / 0);
+            System.out.println(-d % 0.03);
+            System.out.println(nan);
+            System.out.println((int) nan);
+            System.out.println((long) (d * 1e20));
+            System.out.println((int) (-d * 1e20));
+            System.out.println((float) d);
+            System.out.println(d < nan);
+            System.out.println(d > nan);
+            System.out.println(nan != nan);
+            System.out.println(-0.0 * d);
+        }
+        {
+            // Java's digits, which are not always the shortest ones.
+            System.out.println(Float.MIN_VALUE);
+            System.out.println(Float.MAX_VALUE);
+            System.out.println(Double.MIN_VALUE);
+            System.out.println(Double.MAX_VALUE);
+            System.out.println(1.0E23);
+            System.out.println(2e23);
+            System.out.println(2e23f);
+            System.out.println(1e7);
+            System.out.println(1e-5f);
+        }
+    }
+}
@@ -0,0 +1,16 @@
+import com.example.shapes.Named;
+import com.example.shapes.Rectangle;
+import com.example.shapes.Shape;
+import com.example.shapes.Square;
+
+public class Classpath {
+    public static void main(String[] args) {
+        System.out.println("main starts");
+        Shape rectangle = new Rectangle(2, 3);
+        System.out.println(rectangle.area());
+        Named square = new Square(4);
+        square.describe();
+        rectangle.describe();
+        System.out.println(Shape.created);
+    }
+}
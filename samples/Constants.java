@@ -0,0 +1,19 @@
+class Constants {
+    public static void main(String[] args) {
+        System.out.println(100000);
+        System.out.println(-40000);
+        System.out.println(2147483647);
+        System.out.println(1234567890123L);
+        System.out.println(-9223372036854775808L);
+        System.out.println(3.5f);
+        System.out.println(0.1f);
+        System.out.println(1.0e10f);
+        System.out.println(2.5e-5);
+        System.out.println(123456.789);
+        System.out.println(1.0e7);
+        System.out.println(0.001);
+        System.out.println(-42.0);
+        System.out.println(1.0 / 0.0);
+        System.out.println(0.0 / 0.0);
+    }
+}
@@ -0,0 +1,51 @@
+public class Constructor {
+    private int width = 3;
+    private int height;
+    private String name;
+
+    public Constructor() {
+        this(4, "square");
+        height = width;
+    }
+
+    public Constructor(int height, String name) {
+        super();
+        this.height = height;
+        this.name = name;
+    }
+
+    private int area() {
+        return width * height;
+    }
+
+    public String toString() {
+        return name;
+    }
+
+    public int hashCode() {
+        return area();
+    }
+
+    public static void main(String[] args) {
+        Constructor rect = new Constructor(5, "rect");
+        System.out.println(rect.name);
+        System.out.println(rect.area());
+
+        Constructor square = new Constructor();
+        System.out.println(square.name);
+        System.out.println(square.area());
+        System.out.println(square.hashCode());
+
+        try {
+            throw new ArithmeticException("thrown with a message");
+        } catch (ArithmeticException e) {
+            System.out.println(e.getMessage());
+        }
+        RuntimeException cause = new RuntimeException("cause");
+        RuntimeException wrapper = new RuntimeException("wrapper", cause);
+        System.out.println(wrapper.getMessage());
+        System.out.println(wrapper.getCause().getMessage());
+        System.out.println(new Exception(cause).getMessage());
+        System.out.println(new Exception().getMessage());
+    }
+}
@@ -0,0 +1,42 @@
+class DeepRecursion {
+    private final int step;
+
+    DeepRecursion(int step) {
+        this.step = step;
+    }
+
+    static int depth(int n) {
+        return n == 0 ? 0 : depth(n - 1) + 1;
+    }
+
+    long sum(int n) {
+        return n == 0 ? 0 : n + sum(n - step);
+    }
+
+    static int forever(int n) {
+        return forever(n + 1) + 1;
+    }
+
+    static int fail(int n) {
+        if (n == 0) {
+            throw new ArithmeticException("thrown at the bottom");
+        }
+        return fail(n - 1) + 1;
+    }
+
+    public static void main(String[] args) {
+        System.out.println(depth(5000));
+        System.out.println(new DeepRecursion(1).sum(5000));
+        try {
+            forever(0);
+        } catch (StackOverflowError e) {
+            System.out.println("StackOverflowError is caught");
+        }
+        try {
+            System.out.println(fail(3000));
+        } catch (ArithmeticException e) {
+            System.out.println(e.getMessage());
+        }
+        System.out.println(depth(5000));
+    }
+}
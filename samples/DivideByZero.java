@@ -0,0 +1,38 @@
+class DivideByZero {
+    public static void main(String[] args) {
+        {
+            int zero = 0;
+            try {
+                try {
+                    System.out.println(7 / zero);
+                } finally {
+                    System.out.println("finally");
+                }
+            } catch (Exception e) {
+                System.out.println("caught after finally");
+            }
+        }
+
+        int a = 7;
+        int b = 0;
+        try {
+            System.out.println(a / b);
+            System.out.println("not reached");
+        } catch (ArithmeticException e) {
+            System.out.println("caught division by zero");
+        }
+
+        try {
+            System.out.println(a % b);
+        } catch (RuntimeException e) {
+            System.out.println("caught remainder by zero");
+        }
+
+        System.out.println(-a / 2);
+        System.out.println(-a % 2);
+        a = -2147483648;
+        b = -1;
+        System.out.println(a / b);
+        System.out.println(a % b);
+    }
+}
@@ -0,0 +1,16 @@
+class Field {
+    static final int ANSWER = 100000;
+    static final long BIG = 1L << 40;
+    static final double RATIO = 0.75;
+    static final String GREETING = "Hello Field!";
+    static int counter;
+    private int count;
+    protected boolean enabled;
+
+    public static void main(String[] args) {
+        System.out.println(ANSWER);
+        System.out.println(BIG);
+        System.out.println(RATIO);
+        System.out.println(GREETING);
+    }
+}
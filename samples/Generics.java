@@ -0,0 +1,24 @@
+import java.util.List;
+import java.util.Map;
+
+class Generics<K extends Comparable<? super K>, V> implements Comparable<Generics<K, V>> {
+    static Map<String, List<? extends Number>> table;
+    static List<?>[] lists;
+
+    static <T> T first(T a, T b) {
+        return a;
+    }
+
+    static <T extends Number & Comparable<T>, E extends Exception> int[] convert(List<T> values, Map.Entry<String, ? super Integer>[] entries) throws E {
+        return null;
+    }
+
+    public int compareTo(Generics<K, V> other) {
+        return 0;
+    }
+
+    public static void main(String[] args) {
+        Object chosen = first("first", "second");
+        System.out.println(chosen);
+    }
+}
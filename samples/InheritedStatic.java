@@ -0,0 +1,9 @@
+import com.example.statics.Sub;
+
+public class InheritedStatic {
+    public static void main(String[] args) {
+        System.out.println(Sub.inc());
+        System.out.println(Sub.inc());
+        System.out.println(Sub.twice());
+    }
+}
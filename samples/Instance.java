@@ -0,0 +1,55 @@
+class Instance {
+    int count;
+    long total;
+    float ratio;
+    double average;
+    boolean done;
+    char grade;
+    String name;
+    Instance next;
+
+    public static void main(String[] args) {
+        Instance first = new Instance();
+        System.out.println(first.count);
+        System.out.println(first.total);
+        System.out.println(first.ratio);
+        System.out.println(first.average);
+        System.out.println(first.done);
+        System.out.println(first.name);
+
+        first.count = 3;
+        first.total = 1L << 40;
+        first.ratio = 0.5f;
+        first.average = 2.5;
+        first.done = true;
+        first.grade = 'A';
+        first.name = "first";
+        first.count += 4;
+        first.total++;
+        System.out.println(first.count);
+        System.out.println(first.total);
+        System.out.println(first.ratio);
+        System.out.println(first.average);
+        System.out.println(first.done);
+        System.out.println(first.grade);
+        System.out.println(first.name);
+
+        Instance second = new Instance();
+        second.name = "second";
+        first.next = second;
+        System.out.println(first.next.name);
+        System.out.println(second.count);
+        System.out.println(first.next == second);
+
+        try {
+            System.out.println(second.next.count);
+        } catch (NullPointerException e) {
+            System.out.println("null");
+        }
+        try {
+            throw new ArithmeticException();
+        } catch (RuntimeException e) {
+            System.out.println("caught");
+        }
+    }
+}
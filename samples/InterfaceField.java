@@ -0,0 +1,11 @@
+import com.example.fields.Checker;
+import com.example.fields.Failures;
+
+public class InterfaceField {
+    public static void main(String[] args) {
+        System.out.println(Checker.FAILURE.getMessage());
+        System.out.println(Checker.NOTHING);
+        System.out.println(Checker.checks);
+        System.out.println(Checker.FAILURE == Failures.FAILURE);
+    }
+}
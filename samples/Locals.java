@@ -0,0 +1,28 @@
+class Locals {
+    public static void main(String[] args) {
+        int i = 10;
+        long l = 20L;
+        float f = 1.5f;
+        double d = 2.25;
+        String s = "locals";
+        int j = i * 3;
+        long m = l * l;
+        double e = d * f;
+        i += 100;
+        j -= 1000;
+        System.out.println(i);
+        System.out.println(l);
+        System.out.println(f);
+        System.out.println(d);
+        System.out.println(s);
+        System.out.println(j);
+        System.out.println(m);
+        System.out.println(e);
+        for (int k = 0; k < 3; k++) {
+            m += k;
+            e *= 2;
+        }
+        System.out.println(m);
+        System.out.println(e);
+    }
+}
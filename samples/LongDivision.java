@@ -0,0 +1,20 @@
+class LongDivision {
+    public static void main(String[] args) {
+        long l = 10L;
+        try {
+            System.out.println(l / (l - 10));
+        } catch (ArithmeticException e) {
+            System.out.println("caught long division by zero");
+        }
+        try {
+            System.out.println(l % 0L);
+        } catch (Throwable e) {
+            System.out.println("caught long remainder by zero");
+        }
+        System.out.println(l / -3);
+        System.out.println(-l % 3);
+        l = -9223372036854775808L;
+        System.out.println(l / -1);
+        System.out.println(l % -1);
+    }
+}
@@ -0,0 +1,24 @@
+class Loop {
+    public static void main(String[] args) {
+        int sum = 0;
+        for (int i = 1; i <= 10; i++) {
+            sum += i;
+        }
+        System.out.println(sum);
+
+        int n = 5;
+        while (n > 0) {
+            System.out.println(n);
+            n--;
+        }
+
+        do {
+            n += 4;
+        } while (n < 10);
+        System.out.println(n);
+
+        for (int i = 1000; i >= 0; i -= 300) {
+            System.out.println(i);
+        }
+    }
+}
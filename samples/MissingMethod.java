@@ -0,0 +1,25 @@
+import com.example.linkage.Library;
+
+// Compiled against a version of Library that also has removed() and gone().
+class MissingMethod {
+    public static void main(String[] args) {
+        System.out.println(Library.version());
+        try {
+            System.out.println(Library.removed());
+        } catch (NoSuchMethodError e) {
+            System.out.println("static method is missing");
+        }
+        Library library = new Library();
+        System.out.println(library.size());
+        try {
+            System.out.println(library.gone());
+        } catch (NoSuchMethodError e) {
+            System.out.println("instance method is missing");
+        }
+        try {
+            System.out.println(library.gone());
+        } catch (IncompatibleClassChangeError e) {
+            System.out.println("caught as IncompatibleClassChangeError");
+        }
+    }
+}
@@ -0,0 +1,7 @@
+class ModifiedUtf8 {
+    public static void main(String[] args) {
+        System.out.println("nul:\0:");
+        System.out.println("emoji: 😀");
+        System.out.println("é and ☕ and 😀");
+    }
+}
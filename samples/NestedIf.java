@@ -0,0 +1,34 @@
+class NestedIf {
+    public static void main(String[] args) {
+        int a = 7;
+        int b = -3;
+        if (a > 0) {
+            if (b > 0) {
+                System.out.println("a and b are positive");
+            } else {
+                System.out.println("only a is positive");
+            }
+        } else {
+            System.out.println("a is not positive");
+        }
+
+        if (a == 7 && b != 0) {
+            System.out.println("a is 7 and b is not 0");
+        }
+        if (a < b || b == -3) {
+            System.out.println("a is less than b or b is -3");
+        }
+        if (a - b >= 10) {
+            System.out.println(a - b);
+        }
+
+        String s = null;
+        if (s == null) {
+            System.out.println("s is null");
+        }
+        s = "rjvm";
+        if (s != null && s == "rjvm") {
+            System.out.println(s);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+class StackOps {
+    int i;
+    long l;
+
+    static int answer() {
+        return 42;
+    }
+
+    static long big() {
+        return 1L << 50;
+    }
+
+    public static void main(String[] args) {
+        int a, b, c;
+        a = b = c = 7;
+        System.out.println(a + b + c);
+        long x, y;
+        x = y = 1L << 40;
+        System.out.println(x + y);
+        double d, e;
+        d = e = 0.25;
+        System.out.println(d * e);
+
+        StackOps s = new StackOps();
+        int n = s.i = 4;
+        long m = s.l = 1L << 35;
+        System.out.println(n + s.i);
+        System.out.println(m + s.l);
+
+        answer();
+        big();
+
+        int[] ints = new int[2];
+        long[] longs = new long[2];
+        int p = ints[1]++;
+        long q = longs[1]++;
+        int r = ints[1] += 10;
+        long t = longs[1] += 10;
+        System.out.println(p + ints[1] + r);
+        System.out.println(q + longs[1] + t);
+    }
+}
@@ -0,0 +1,37 @@
+import java.io.PrintStream;
+
+class StaticField {
+    static int counter;
+    static long total;
+    static double scale;
+    static boolean ready;
+    static String label;
+
+    static void record(int n) {
+        counter++;
+        total += n;
+    }
+
+    public static void main(String[] args) {
+        System.out.println(counter);
+        System.out.println(total);
+        System.out.println(scale);
+        System.out.println(ready);
+        System.out.println(label);
+
+        for (int i = 1; i <= 10; i++) {
+            record(i * i);
+        }
+        scale = 1.5;
+        ready = counter == 10;
+        label = "recorded";
+        System.out.println(counter);
+        System.out.println(total);
+        System.out.println(scale * total);
+        System.out.println(ready);
+        System.out.println(label);
+
+        PrintStream out = System.out;
+        out.println(out == System.out);
+    }
+}
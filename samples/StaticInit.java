@@ -0,0 +1,26 @@
+class StaticInit {
+    static int base = 40;
+    static long[] unused;
+    static long square;
+    static String greeting = "initialized";
+
+    static {
+        System.out.println("static block");
+        base += 2;
+        square = (long) base * base;
+    }
+
+    static int twice(int n) {
+        return n * 2;
+    }
+
+    static int doubled = twice(base);
+
+    public static void main(String[] args) {
+        System.out.println("main");
+        System.out.println(base);
+        System.out.println(square);
+        System.out.println(greeting);
+        System.out.println(doubled);
+    }
+}
@@ -0,0 +1,49 @@
+class StaticMethod {
+    static long mix(int i, long l, float f, double d) {
+        return i + l + (long) f + (long) d;
+    }
+
+    static double average(double a, double b) {
+        return (a + b) / 2;
+    }
+
+    static float half(float f) {
+        return f / 2;
+    }
+
+    static boolean isEven(int n) {
+        return n % 2 == 0;
+    }
+
+    static String greeting() {
+        return "hello";
+    }
+
+    static long factorial(int n) {
+        return n <= 1 ? 1 : n * factorial(n - 1);
+    }
+
+    static int divide(int a, int b) {
+        return a / b;
+    }
+
+    static void show(int n) {
+        System.out.println(n);
+    }
+
+    public static void main(String[] args) {
+        System.out.println(mix(1, 2L, 3.5f, 4.5));
+        System.out.println(average(1.0, 2.0));
+        System.out.println(half(5.0f));
+        System.out.println(isEven(10));
+        System.out.println(isEven(7));
+        System.out.println(greeting());
+        System.out.println(factorial(20));
+        show(42);
+        try {
+            divide(1, 0);
+        } catch (ArithmeticException e) {
+            System.out.println("caught");
+        }
+    }
+}
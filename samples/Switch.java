@@ -0,0 +1,41 @@
+class Switch {
+    public static void main(String[] args) {
+        for (int i = -1; i <= 5; i++) {
+            switch (i) {
+                case 0:
+                    System.out.println("zero");
+                    break;
+                case 1:
+                    System.out.println("one");
+                    break;
+                case 2:
+                case 3:
+                    System.out.println("two or three");
+                    break;
+                case 4:
+                    System.out.println("four");
+                default:
+                    System.out.println("default");
+            }
+        }
+
+        for (int n = -500; n <= 1500; n += 250) {
+            switch (n) {
+                case -500:
+                    System.out.println("minus five hundred");
+                    break;
+                case 0:
+                    System.out.println("zero");
+                    break;
+                case 1000:
+                    System.out.println("thousand");
+                    break;
+                case 1500:
+                    System.out.println(n);
+                    break;
+                default:
+                    System.out.println("sparse default");
+            }
+        }
+    }
+}
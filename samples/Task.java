@@ -0,0 +1,16 @@
+public class Task implements Runnable {
+    private final String name;
+
+    Task(String name) {
+        this.name = name;
+    }
+
+    public void run() {
+        System.out.println(name);
+    }
+
+    public static void main(String[] args) {
+        Runnable task = new Task("task runs");
+        task.run();
+    }
+}
@@ -0,0 +1,50 @@
+class VirtualCall {
+    int value;
+    long calls;
+
+    int add(int n) {
+        calls++;
+        value += n;
+        return value;
+    }
+
+    long sumTo(int n) {
+        calls++;
+        return n == 0 ? 0 : n + sumTo(n - 1);
+    }
+
+    double scaled(double factor) {
+        return value * factor;
+    }
+
+    VirtualCall self() {
+        return this;
+    }
+
+    void report() {
+        System.out.print("value=");
+        System.out.println(value);
+        System.out.print("calls=");
+        System.out.println(calls);
+    }
+
+    public static void main(String[] args) {
+        VirtualCall counter = new VirtualCall();
+        counter.add(5);
+        System.out.println(counter.add(10));
+        System.out.println(counter.sumTo(100));
+        System.out.println(counter.self().scaled(0.5));
+        counter.report();
+        System.out.print('x');
+        System.out.print(true);
+        System.out.print(1.5f);
+        System.out.println();
+
+        VirtualCall missing = null;
+        try {
+            missing.report();
+        } catch (NullPointerException e) {
+            System.out.println("null receiver");
+        }
+    }
+}
@@ -0,0 +1,146 @@
+// Declares enough locals that the last ones need the wide forms of load, store and iinc.
+class WideLocals {
+    public static void main(String[] args) {
+        double d0 = 0.5;
+        double d1 = 1.5;
+        double d2 = 2.5;
+        double d3 = 3.5;
+        double d4 = 4.5;
+        double d5 = 5.5;
+        double d6 = 6.5;
+        double d7 = 7.5;
+        double d8 = 8.5;
+        double d9 = 9.5;
+        double d10 = 10.5;
+        double d11 = 11.5;
+        double d12 = 12.5;
+        double d13 = 13.5;
+        double d14 = 14.5;
+        double d15 = 15.5;
+        double d16 = 16.5;
+        double d17 = 17.5;
+        double d18 = 18.5;
+        double d19 = 19.5;
+        double d20 = 20.5;
+        double d21 = 21.5;
+        double d22 = 22.5;
+        double d23 = 23.5;
+        double d24 = 24.5;
+        double d25 = 25.5;
+        double d26 = 26.5;
+        double d27 = 27.5;
+        double d28 = 28.5;
+        double d29 = 29.5;
+        double d30 = 30.5;
+        double d31 = 31.5;
+        double d32 = 32.5;
+        double d33 = 33.5;
+        double d34 = 34.5;
+        double d35 = 35.5;
+        double d36 = 36.5;
+        double d37 = 37.5;
+        double d38 = 38.5;
+        double d39 = 39.5;
+        double d40 = 40.5;
+        double d41 = 41.5;
+        double d42 = 42.5;
+        double d43 = 43.5;
+        double d44 = 44.5;
+        double d45 = 45.5;
+        double d46 = 46.5;
+        double d47 = 47.5;
+        double d48 = 48.5;
+        double d49 = 49.5;
+        double d50 = 50.5;
+        double d51 = 51.5;
+        double d52 = 52.5;
+        double d53 = 53.5;
+        double d54 = 54.5;
+        double d55 = 55.5;
+        double d56 = 56.5;
+        double d57 = 57.5;
+        double d58 = 58.5;
+        double d59 = 59.5;
+        double d60 = 60.5;
+        double d61 = 61.5;
+        double d62 = 62.5;
+        double d63 = 63.5;
+        double d64 = 64.5;
+        double d65 = 65.5;
+        double d66 = 66.5;
+        double d67 = 67.5;
+        double d68 = 68.5;
+        double d69 = 69.5;
+        double d70 = 70.5;
+        double d71 = 71.5;
+        double d72 = 72.5;
+        double d73 = 73.5;
+        double d74 = 74.5;
+        double d75 = 75.5;
+        double d76 = 76.5;
+        double d77 = 77.5;
+        double d78 = 78.5;
+        double d79 = 79.5;
+        double d80 = 80.5;
+        double d81 = 81.5;
+        double d82 = 82.5;
+        double d83 = 83.5;
+        double d84 = 84.5;
+        double d85 = 85.5;
+        double d86 = 86.5;
+        double d87 = 87.5;
+        double d88 = 88.5;
+        double d89 = 89.5;
+        double d90 = 90.5;
+        double d91 = 91.5;
+        double d92 = 92.5;
+        double d93 = 93.5;
+        double d94 = 94.5;
+        double d95 = 95.5;
+        double d96 = 96.5;
+        double d97 = 97.5;
+        double d98 = 98.5;
+        double d99 = 99.5;
+        double d100 = 100.5;
+        double d101 = 101.5;
+        double d102 = 102.5;
+        double d103 = 103.5;
+        double d104 = 104.5;
+        double d105 = 105.5;
+        double d106 = 106.5;
+        double d107 = 107.5;
+        double d108 = 108.5;
+        double d109 = 109.5;
+        double d110 = 110.5;
+        double d111 = 111.5;
+        double d112 = 112.5;
+        double d113 = 113.5;
+        double d114 = 114.5;
+        double d115 = 115.5;
+        double d116 = 116.5;
+        double d117 = 117.5;
+        double d118 = 118.5;
+        double d119 = 119.5;
+        double d120 = 120.5;
+        double d121 = 121.5;
+        double d122 = 122.5;
+        double d123 = 123.5;
+        double d124 = 124.5;
+        double d125 = 125.5;
+        double d126 = 126.5;
+        double d127 = 127.5;
+        int i = 1;
+        long l = 2L;
+        float f = 3.0f;
+        String s = "wide";
+        i += 1000;
+        l = l * 1000000007L;
+        f = f / 4;
+        d127 += i;
+        System.out.println(i);
+        System.out.println(l);
+        System.out.println(f);
+        System.out.println(s);
+        System.out.println(d0 + d127);
+    }
+}
@@ -0,0 +1,5 @@
+package com.example.fields;
+
+public class Checker implements MoreFailures {
+    public static int checks = 3;
+}
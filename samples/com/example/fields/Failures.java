@@ -0,0 +1,5 @@
+package com.example.fields;
+
+public interface Failures {
+    RuntimeException FAILURE = new RuntimeException("failure");
+}
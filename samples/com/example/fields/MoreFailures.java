@@ -0,0 +1,5 @@
+package com.example.fields;
+
+public interface MoreFailures extends Failures {
+    Object NOTHING = null;
+}
@@ -0,0 +1,11 @@
+package com.example.linkage;
+
+public class Library {
+    public static int version() {
+        return 2;
+    }
+
+    public int size() {
+        return 3;
+    }
+}
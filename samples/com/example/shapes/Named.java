@@ -0,0 +1,9 @@
+package com.example.shapes;
+
+public interface Named {
+    String name();
+
+    default void describe() {
+        System.out.println(name());
+    }
+}
@@ -0,0 +1,25 @@
+package com.example.shapes;
+
+public class Rectangle extends Shape {
+    protected final int width;
+    protected final int height;
+
+    public Rectangle(int width, int height) {
+        this("rectangle", width, height);
+    }
+
+    protected Rectangle(String name, int width, int height) {
+        super(name);
+        this.width = width;
+        this.height = height;
+    }
+
+    public int area() {
+        return width * height;
+    }
+
+    public void describe() {
+        super.describe();
+        System.out.println(area());
+    }
+}
@@ -0,0 +1,22 @@
+package com.example.shapes;
+
+public abstract class Shape implements Named {
+    public static int created;
+
+    static {
+        System.out.println("Shape is initialized");
+    }
+
+    private final String name;
+
+    protected Shape(String name) {
+        this.name = name;
+        created++;
+    }
+
+    public abstract int area();
+
+    public String name() {
+        return name;
+    }
+}
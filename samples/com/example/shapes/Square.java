@@ -0,0 +1,17 @@
+package com.example.shapes;
+
+public class Square extends Rectangle {
+    static {
+        System.out.println("Square is initialized");
+    }
+
+    public Square(int side) {
+        super("square", side, side);
+    }
+
+    public void describe() {
+        System.out.println("a square of side");
+        System.out.println(width);
+        super.describe();
+    }
+}
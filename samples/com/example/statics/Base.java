@@ -0,0 +1,13 @@
+package com.example.statics;
+
+public class Base {
+    static {
+        System.out.println("Base is initialized");
+    }
+
+    private static int count;
+
+    public static int inc() {
+        return ++count;
+    }
+}
@@ -0,0 +1,12 @@
+package com.example.statics;
+
+public class Sub extends Base {
+    static {
+        System.out.println("Sub is initialized");
+    }
+
+    public static int twice() {
+        inc();
+        return inc();
+    }
+}
@@ -0,0 +1,11 @@
+package com.example.dump;
+
+public class Lambda {
+    public static Runnable printer(String name) {
+        return () -> System.out.println(name);
+    }
+
+    public static String greet(String name, int times) {
+        return "Hello, " + name + " x" + times;
+    }
+}
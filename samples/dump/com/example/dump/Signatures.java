@@ -0,0 +1,11 @@
+package com.example.dump;
+
+import java.util.List;
+import java.util.Map;
+
+public abstract class Signatures<K extends Comparable<? super K>, V> implements Comparable<Signatures<K, V>> {
+    private Map<K, List<? extends V>> table;
+    private List<?>[] lists;
+
+    public abstract <T extends Number & Comparable<T>, E extends Exception> T[] convert(Map.Entry<K, ? super T> entry) throws E;
+}
@@ -0,0 +1,3 @@
+module com.example.dump {
+    exports com.example.dump;
+}
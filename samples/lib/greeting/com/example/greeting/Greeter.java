@@ -0,0 +1,15 @@
+package com.example.greeting;
+
+public class Greeter {
+    private final String greeting;
+
+    public Greeter(String greeting) {
+        this.greeting = greeting;
+    }
+
+    public void greet(String name) {
+        System.out.print(greeting);
+        System.out.print(", ");
+        System.out.println(name);
+    }
+}
@@ -1,19 +1,45 @@
 pub mod attribute;
 pub mod constant_pool;
 mod constant_pool_table;
+pub mod descriptor;
+pub mod field;
 pub mod method;
+pub mod signature;
 
 use std::fmt;
 use std::io::Cursor;
 
 use byteorder::{BigEndian, ReadBytesExt};
 
+use attribute::{Attribute, NotFoundAttribute};
 use constant_pool::ConstantPool;
-use constant_pool_table::ConstantPoolTable;
+use constant_pool_table::{ConstantPoolTable, NotFoundClassInfo};
+use field::FieldInfo;
 use method::MethodInfo;
+use signature::{ClassSignature, SignatureError};
 
 pub type ReaderResult<T> = Result<(T, Cursor<Vec<u8>>), Box<dyn std::error::Error>>;
 
+#[derive(Debug)]
+pub struct TrailingBytesError {
+    remaining: u64,
+}
+
+impl TrailingBytesError {
+    pub fn new(remaining: u64) -> Self {
+        Self { remaining }
+    }
+}
+
+impl std::error::Error for TrailingBytesError {}
+
+impl fmt::Display for TrailingBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bytes remain after the end of the class file", self.remaining)?;
+        Ok(())
+    }
+}
+
 // https://docs.oracle.com/javase/specs/jvms/se7/html/jvms-4.html
 #[repr(C)]
 pub struct Class {
@@ -26,13 +52,13 @@ pub struct Class {
     this_class: u16,
     pub super_class: u16,
     interfaces_count: u16,
-    interfaces: Vec<u8>,
+    pub interfaces: Vec<String>,
     field_count: u16,
-    field_info: Vec<u8>,
+    pub fields: Vec<FieldInfo>,
     method_count: u16,
     pub methods: Vec<MethodInfo>,
     attributes_count: u16,
-    attribute_info: Vec<u8>,
+    pub attribute_info: Vec<Attribute>,
 }
 
 impl Class {
@@ -41,16 +67,12 @@ impl Class {
         let minor_version = rdr.read_u16::<BigEndian>()?;
         let major_version = rdr.read_u16::<BigEndian>()?;
         let constant_pool_count = rdr.read_u16::<BigEndian>()?;
-        let (cp_info, mut rdr) =
-            (0..constant_pool_count - 1).try_fold((ConstantPoolTable::new(), rdr), |(mut cp_table, rdr), _i| {
-                match ConstantPool::new(rdr) {
-                    Ok((constant_pool, rdr2)) => {
-                        cp_table.push(constant_pool);
-                        Ok((cp_table, rdr2))
-                    }
-                    Err(err) => Err(err),
-                }
-            })?;
+        let mut cp_info = ConstantPoolTable::new();
+        while cp_info.len() + 1 < usize::from(constant_pool_count) {
+            let (constant_pool, rdr2) = ConstantPool::new(rdr)?;
+            cp_info.push(constant_pool);
+            rdr = rdr2;
+        }
 
         let utf8_table = cp_info.utf8info();
 
@@ -59,10 +81,24 @@ impl Class {
         let super_class = rdr.read_u16::<BigEndian>()?;
 
         let interfaces_count = rdr.read_u16::<BigEndian>()?;
-        let interfaces = Vec::new(); // TODO: unimplemented!
+        let (interfaces, mut rdr) = (0..interfaces_count).try_fold((Vec::new(), rdr), |(mut ret, mut rdr), _i| {
+            let index = rdr.read_u16::<BigEndian>()?;
+            let name = cp_info.class_name(index).ok_or_else(|| NotFoundClassInfo::new(index))?;
+            ret.push(name);
+            Ok::<_, Box<dyn std::error::Error>>((ret, rdr))
+        })?;
 
         let field_count = rdr.read_u16::<BigEndian>()?;
-        let field_info = Vec::new(); // TODO: unimplemented!
+        let (fields, mut rdr) =
+            (0..field_count).try_fold((Vec::new(), rdr), |(mut ret, rdr), _i| {
+                match FieldInfo::new(rdr, &utf8_table) {
+                    Ok((field_info, rdr2)) => {
+                        ret.push(field_info);
+                        Ok((ret, rdr2))
+                    }
+                    Err(err) => Err(err),
+                }
+            })?;
 
         let method_count = rdr.read_u16::<BigEndian>()?;
         let (methods, mut rdr) = (0..method_count).try_fold((Vec::new(), rdr), |(mut ret, rdr), _i| {
@@ -76,7 +112,20 @@ impl Class {
         })?;
 
         let attributes_count = rdr.read_u16::<BigEndian>()?;
-        let attribute_info = Vec::new(); // TODO: unimplemented!
+        let (attribute_info, rdr) = (0..attributes_count).try_fold((Vec::new(), rdr), |(mut ret, rdr), _i| {
+            match Attribute::new(rdr, &utf8_table) {
+                Ok((ai, rdr2)) => {
+                    ret.push(ai);
+                    Ok((ret, rdr2))
+                }
+                Err(err) => Err(err),
+            }
+        })?;
+
+        let remaining = rdr.get_ref().len() as u64 - rdr.position();
+        if remaining != 0 {
+            return Err(Box::new(TrailingBytesError::new(remaining)));
+        }
 
         Ok((
             Self {
@@ -91,7 +140,7 @@ impl Class {
                 interfaces_count,
                 interfaces,
                 field_count,
-                field_info,
+                fields,
                 method_count,
                 methods,
                 attributes_count,
@@ -102,27 +151,81 @@ impl Class {
     }
 }
 
+impl Class {
+    // Same as `new`, but rejects attributes that rjvm does not know instead of keeping them.
+    pub fn new_strict(rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let (class, rdr) = Self::new(rdr)?;
+        if let Some(name) = class.unknown_attribute_names().first() {
+            return Err(Box::new(NotFoundAttribute::new(name.to_string())));
+        }
+        Ok((class, rdr))
+    }
+
+    pub fn unknown_attribute_names(&self) -> Vec<&str> {
+        self.attribute_info
+            .iter()
+            .chain(self.fields.iter().flat_map(|field| &field.attribute_info))
+            .chain(self.methods.iter().flat_map(|method| &method.attribute_info))
+            .flat_map(|ai| ai.unknown_names())
+            .collect()
+    }
+
+    // The generic superclass and superinterfaces from the Signature attribute, if the class has one.
+    pub fn signature(&self) -> Option<Result<ClassSignature, SignatureError>> {
+        self.attribute_info.iter().find_map(|attr| match attr {
+            Attribute::Signature(signature) => Some(ClassSignature::new(&signature.signature)),
+            _ => None,
+        })
+    }
+
+    pub fn this_class_name(&self) -> Option<String> {
+        self.cp_info.class_name(self.this_class)
+    }
+
+    // Methods are identified by both name and descriptor because of overloading.
+    pub fn method_index(&self, name: &str, descriptor: &str) -> Option<usize> {
+        self.methods
+            .iter()
+            .position(|method| method.name == name && method.descriptor == descriptor)
+    }
+}
+
 impl fmt::Debug for Class {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "Class")?;
         writeln!(f, "\t magic: {:x}", self.magic)?;
         writeln!(f, "\t minor_version: {}", self.minor_version)?;
         writeln!(f, "\t major_version: {}", self.major_version)?;
+        if let Some(signature) = self.signature() {
+            match signature {
+                Ok(signature) => writeln!(f, "\t generic type: {}", signature)?,
+                Err(err) => writeln!(f, "\t generic type: {}", err)?,
+            }
+        }
 
         writeln!(f, "ConstantPool")?;
-        for (i, constant_pool) in self.cp_info.iter().enumerate() {
-            writeln!(f, "\t #{:2} {}", i + 1, constant_pool)?;
+        for (i, constant_pool) in self.cp_info.iter() {
+            writeln!(f, "\t #{:2} {}", i, constant_pool)?;
         }
         writeln!(f, "Interface")?;
         writeln!(f, "\t interface_count: {}", self.interfaces_count)?;
+        for interface in &self.interfaces {
+            writeln!(f, "\t {}", interface)?;
+        }
         writeln!(f, "Field")?;
         writeln!(f, "\t field_count: {}", self.field_count)?;
+        for field in &self.fields {
+            writeln!(f, "{}", field)?;
+        }
         writeln!(f, "Method")?;
         for method in &self.methods {
             writeln!(f, "{}", method)?;
         }
         writeln!(f, "Attribute")?;
-        writeln!(f, "\t attributes_count: {}", self.field_count)?;
+        writeln!(f, "\t attributes_count: {}", self.attributes_count)?;
+        for ai in &self.attribute_info {
+            write!(f, "{}", ai)?;
+        }
         Ok(())
     }
 }
@@ -1,8 +1,20 @@
+pub mod bootstrap_methods;
 pub mod code;
+pub mod constant_value;
+pub mod enclosing_method;
+pub mod inner_classes;
 mod line_number_table;
+pub mod nest_host;
+pub mod nest_members;
+pub mod permitted_subclasses;
+pub mod record;
+pub mod signature;
+pub mod source_file;
+mod stack_map_table;
 
 use std::fmt;
 use std::io::Cursor;
+use std::io::Read;
 
 use byteorder::{BigEndian, ReadBytesExt};
 
@@ -30,41 +42,187 @@ impl fmt::Display for NotFoundAttribute {
     }
 }
 
+#[derive(Debug)]
+pub struct AttributeLengthMismatch {
+    name: String,
+    expected: u32,
+    actual: u64,
+}
+
+impl std::error::Error for AttributeLengthMismatch {}
+
+impl AttributeLengthMismatch {
+    pub fn new(name: String, expected: u32, actual: u64) -> Self {
+        Self { name, expected, actual }
+    }
+}
+
+impl fmt::Display for AttributeLengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "attribute {} declares {} bytes but {} bytes were read",
+            self.name, self.expected, self.actual
+        )?;
+        Ok(())
+    }
+}
+
+// Reads `length` bytes that a class file claims to follow. Nothing is allocated for the claim up
+// front, so a huge length in a truncated class file fails with UnexpectedEof instead of exhausting
+// memory.
+pub(crate) fn read_bytes(rdr: &mut Cursor<Vec<u8>>, length: u32) -> std::io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    rdr.take(length.into()).read_to_end(&mut bytes)?;
+    if bytes.len() as u64 != u64::from(length) {
+        return Err(std::io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(bytes)
+}
+
 #[derive(Debug, Clone)]
 // https://docs.oracle.com/javase/specs/jvms/se7/html/jvms-4.html#jvms-4.7
 pub enum Attribute {
     Code(code::CodeAttribute),
+    ConstantValue(constant_value::ConstantValueAttribute),
     LineNumberTable(line_number_table::LineNumberTableAttribute),
+    StackMapTable(stack_map_table::StackMapTableAttribute),
+    SourceFile(source_file::SourceFileAttribute),
+    InnerClasses(inner_classes::InnerClassesAttribute),
+    EnclosingMethod(enclosing_method::EnclosingMethodAttribute),
+    Signature(signature::SignatureAttribute),
+    BootstrapMethods(bootstrap_methods::BootstrapMethodsAttribute),
+    NestHost(nest_host::NestHostAttribute),
+    NestMembers(nest_members::NestMembersAttribute),
+    PermittedSubclasses(permitted_subclasses::PermittedSubclassesAttribute),
+    Record(record::RecordAttribute),
+    // Attributes that rjvm does not interpret are kept as they are (JVMS 4.7.1).
+    Unknown { name: String, bytes: Vec<u8> },
 }
 
 impl Attribute {
+    // Names of the unknown attributes in this attribute, including the ones nested in it.
+    pub fn unknown_names(&self) -> Vec<&str> {
+        match self {
+            Self::Unknown { name, .. } => vec![name],
+            Self::Code(attribute) => attribute
+                .attribute_info
+                .iter()
+                .flat_map(|ai| ai.unknown_names())
+                .collect(),
+            Self::Record(attribute) => attribute
+                .components
+                .iter()
+                .flat_map(|component| &component.attribute_info)
+                .flat_map(|ai| ai.unknown_names())
+                .collect(),
+            _ => vec![],
+        }
+    }
+
     pub fn new(mut rdr: Cursor<Vec<u8>>, utf8_table: &Utf8Table) -> ReaderResult<Self> {
         let attribute_name_index = rdr.read_u16::<BigEndian>()?;
-        let _attribute_length = rdr.read_u32::<BigEndian>()?;
+        let attribute_length = rdr.read_u32::<BigEndian>()?;
         let attribute_name = utf8_table
             .get(&attribute_name_index)
             .ok_or_else(|| NotFoundUtf8::new(attribute_name_index, utf8_table.clone()))?
             .to_string();
 
-        match &*attribute_name {
+        let start = rdr.position();
+        let (attribute, rdr) = match &*attribute_name {
             "Code" => {
                 let (attribute, rdr) = code::CodeAttribute::new(rdr, utf8_table)?;
-                Ok((Self::Code(attribute), rdr))
+                (Self::Code(attribute), rdr)
+            }
+            "ConstantValue" => {
+                let (attribute, rdr) = constant_value::ConstantValueAttribute::new(rdr)?;
+                (Self::ConstantValue(attribute), rdr)
             }
             "LineNumberTable" => {
                 let (attribute, rdr) = line_number_table::LineNumberTableAttribute::new(rdr)?;
-                Ok((Self::LineNumberTable(attribute), rdr))
+                (Self::LineNumberTable(attribute), rdr)
+            }
+            "StackMapTable" => {
+                let (attribute, rdr) = stack_map_table::StackMapTableAttribute::new(rdr, attribute_length)?;
+                (Self::StackMapTable(attribute), rdr)
+            }
+            "SourceFile" => {
+                let (attribute, rdr) = source_file::SourceFileAttribute::new(rdr, utf8_table)?;
+                (Self::SourceFile(attribute), rdr)
+            }
+            "InnerClasses" => {
+                let (attribute, rdr) = inner_classes::InnerClassesAttribute::new(rdr)?;
+                (Self::InnerClasses(attribute), rdr)
+            }
+            "EnclosingMethod" => {
+                let (attribute, rdr) = enclosing_method::EnclosingMethodAttribute::new(rdr)?;
+                (Self::EnclosingMethod(attribute), rdr)
             }
-            name => Err(Box::new(NotFoundAttribute::new(name.to_string()))),
+            "Signature" => {
+                let (attribute, rdr) = signature::SignatureAttribute::new(rdr, utf8_table)?;
+                (Self::Signature(attribute), rdr)
+            }
+            "BootstrapMethods" => {
+                let (attribute, rdr) = bootstrap_methods::BootstrapMethodsAttribute::new(rdr)?;
+                (Self::BootstrapMethods(attribute), rdr)
+            }
+            "NestHost" => {
+                let (attribute, rdr) = nest_host::NestHostAttribute::new(rdr)?;
+                (Self::NestHost(attribute), rdr)
+            }
+            "NestMembers" => {
+                let (attribute, rdr) = nest_members::NestMembersAttribute::new(rdr)?;
+                (Self::NestMembers(attribute), rdr)
+            }
+            "PermittedSubclasses" => {
+                let (attribute, rdr) = permitted_subclasses::PermittedSubclassesAttribute::new(rdr)?;
+                (Self::PermittedSubclasses(attribute), rdr)
+            }
+            "Record" => {
+                let (attribute, rdr) = record::RecordAttribute::new(rdr, utf8_table)?;
+                (Self::Record(attribute), rdr)
+            }
+            _ => {
+                let bytes = read_bytes(&mut rdr, attribute_length)?;
+                (
+                    Self::Unknown {
+                        name: attribute_name.clone(),
+                        bytes,
+                    },
+                    rdr,
+                )
+            }
+        };
+
+        let consumed = rdr.position() - start;
+        if consumed != u64::from(attribute_length) {
+            return Err(Box::new(AttributeLengthMismatch::new(
+                attribute_name,
+                attribute_length,
+                consumed,
+            )));
         }
+        Ok((attribute, rdr))
     }
 }
 
 impl fmt::Display for Attribute {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &*self {
+        match self {
             Self::Code(attribute) => write!(f, "{:?}", attribute)?,
+            Self::ConstantValue(attribute) => write!(f, "{:?}", attribute)?,
             Self::LineNumberTable(attribute) => write!(f, "{:?}", attribute)?,
+            Self::StackMapTable(attribute) => write!(f, "{:?}", attribute)?,
+            Self::SourceFile(attribute) => write!(f, "{:?}", attribute)?,
+            Self::InnerClasses(attribute) => write!(f, "{:?}", attribute)?,
+            Self::EnclosingMethod(attribute) => write!(f, "{:?}", attribute)?,
+            Self::Signature(attribute) => write!(f, "{:?}", attribute)?,
+            Self::BootstrapMethods(attribute) => write!(f, "{:?}", attribute)?,
+            Self::NestHost(attribute) => write!(f, "{:?}", attribute)?,
+            Self::NestMembers(attribute) => write!(f, "{:?}", attribute)?,
+            Self::PermittedSubclasses(attribute) => write!(f, "{:?}", attribute)?,
+            Self::Record(attribute) => write!(f, "{:?}", attribute)?,
+            Self::Unknown { name, bytes } => writeln!(f, "\t {}: {} bytes", name, bytes.len())?,
         }
         Ok(())
     }
@@ -0,0 +1,91 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::ReaderResult;
+
+#[derive(Clone)]
+#[repr(C)]
+pub struct BootstrapMethod {
+    pub bootstrap_method_ref: u16,
+    num_bootstrap_arguments: u16,
+    pub bootstrap_arguments: Vec<u16>,
+}
+
+impl BootstrapMethod {
+    pub fn new(mut rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let bootstrap_method_ref = rdr.read_u16::<BigEndian>()?;
+        let num_bootstrap_arguments = rdr.read_u16::<BigEndian>()?;
+        let (bootstrap_arguments, rdr) =
+            (0..num_bootstrap_arguments).try_fold((Vec::new(), rdr), |(mut ret, mut rdr), _i| {
+                match rdr.read_u16::<BigEndian>() {
+                    Ok(argument) => {
+                        ret.push(argument);
+                        Ok((ret, rdr))
+                    }
+                    Err(err) => Err(err),
+                }
+            })?;
+
+        Ok((
+            Self {
+                bootstrap_method_ref,
+                num_bootstrap_arguments,
+                bootstrap_arguments,
+            },
+            rdr,
+        ))
+    }
+}
+
+impl fmt::Debug for BootstrapMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.bootstrap_method_ref)?;
+        for argument in &self.bootstrap_arguments {
+            write!(f, " #{}", argument)?;
+        }
+        Ok(())
+    }
+}
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.7.23
+#[derive(Clone)]
+#[repr(C)]
+pub struct BootstrapMethodsAttribute {
+    num_bootstrap_methods: u16,
+    pub bootstrap_methods: Vec<BootstrapMethod>,
+}
+
+impl BootstrapMethodsAttribute {
+    pub fn new(mut rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let num_bootstrap_methods = rdr.read_u16::<BigEndian>()?;
+        let (bootstrap_methods, rdr) = (0..num_bootstrap_methods).try_fold(
+            (Vec::new(), rdr),
+            |(mut ret, rdr), _i| match BootstrapMethod::new(rdr) {
+                Ok((bootstrap_method, rdr2)) => {
+                    ret.push(bootstrap_method);
+                    Ok((ret, rdr2))
+                }
+                Err(err) => Err(err),
+            },
+        )?;
+
+        Ok((
+            Self {
+                num_bootstrap_methods,
+                bootstrap_methods,
+            },
+            rdr,
+        ))
+    }
+}
+
+impl fmt::Debug for BootstrapMethodsAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, bootstrap_method) in self.bootstrap_methods.iter().enumerate() {
+            writeln!(f, "\t bootstrap_method: {}: {:?}", i, bootstrap_method)?;
+        }
+        Ok(())
+    }
+}
@@ -6,14 +6,14 @@ use byteorder::{BigEndian, ReadBytesExt};
 use crate::class::attribute::Attribute;
 use crate::class::constant_pool_table::Utf8Table;
 use crate::class::ReaderResult;
-use crate::instruction::Instruction;
+use crate::instruction::{Instruction, InstructionStream};
 
 #[derive(Clone)]
-struct Exception {
-    start_pc: u16,
-    end_pc: u16,
-    handle_pc: u16,
-    catch_type: u16,
+pub struct Exception {
+    pub start_pc: u16,
+    pub end_pc: u16,
+    pub handle_pc: u16,
+    pub catch_type: u16,
 }
 
 impl Exception {
@@ -50,14 +50,14 @@ impl fmt::Display for Exception {
 // https://docs.oracle.com/javase/specs/jvms/se7/html/jvms-4.html#jvms-4.7.3
 #[repr(C)]
 pub struct CodeAttribute {
-    max_stack: u16,
-    max_locals: u16,
+    pub max_stack: u16,
+    pub max_locals: u16,
     pub code_length: u32,
-    pub instructions: Vec<Instruction>,
+    pub instructions: InstructionStream,
     exception_table_length: u16,
-    exception_table: Vec<Exception>,
+    pub exception_table: Vec<Exception>,
     attributes_count: u16,
-    attribute_info: Vec<Attribute>,
+    pub attribute_info: Vec<Attribute>,
 }
 
 impl CodeAttribute {
@@ -65,7 +65,7 @@ impl CodeAttribute {
         let max_stack = rdr.read_u16::<BigEndian>()?;
         let max_locals = rdr.read_u16::<BigEndian>()?;
         let code_length = rdr.read_u32::<BigEndian>()?;
-        let (mut code, mut rdr) =
+        let (code, mut rdr) =
             (0..code_length).try_fold((Vec::new(), rdr), |(mut ret, mut rdr), _i| match rdr.read_u8() {
                 Ok(value) => {
                     ret.push(value);
@@ -74,8 +74,7 @@ impl CodeAttribute {
                 Err(err) => Err(err),
             })?;
 
-        code.reverse();
-        let instructions = Instruction::from_codes(code);
+        let instructions = Instruction::from_codes(&code)?;
 
         let exception_table_length = rdr.read_u16::<BigEndian>()?;
         let (exception_table, mut rdr) =
@@ -122,8 +121,8 @@ impl fmt::Debug for CodeAttribute {
             write!(f, "{}", at)?;
         }
         writeln!(f, "\t instructsion: ")?;
-        for i in &self.instructions {
-            writeln!(f, "\t     {} ", i)?;
+        for (offset, i) in self.instructions.iter() {
+            writeln!(f, "\t     {:>4}: {} ", offset, i)?;
         }
         Ok(())
     }
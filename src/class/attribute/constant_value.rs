@@ -0,0 +1,27 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::ReaderResult;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.7.2
+#[derive(Clone)]
+#[repr(C)]
+pub struct ConstantValueAttribute {
+    pub constantvalue_index: u16,
+}
+
+impl ConstantValueAttribute {
+    pub fn new(mut rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let constantvalue_index = rdr.read_u16::<BigEndian>()?;
+        Ok((Self { constantvalue_index }, rdr))
+    }
+}
+
+impl fmt::Debug for ConstantValueAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\t constant_value: #{}", self.constantvalue_index)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::ReaderResult;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.7.7
+#[derive(Clone)]
+#[repr(C)]
+pub struct EnclosingMethodAttribute {
+    pub class_index: u16,
+    pub method_index: u16,
+}
+
+impl EnclosingMethodAttribute {
+    pub fn new(mut rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let class_index = rdr.read_u16::<BigEndian>()?;
+        let method_index = rdr.read_u16::<BigEndian>()?;
+        Ok((
+            Self {
+                class_index,
+                method_index,
+            },
+            rdr,
+        ))
+    }
+}
+
+impl fmt::Debug for EnclosingMethodAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\t enclosing_method: #{}.#{}", self.class_index, self.method_index)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::ReaderResult;
+
+#[derive(Clone)]
+pub struct InnerClass {
+    pub inner_class_info_index: u16,
+    pub outer_class_info_index: u16,
+    pub inner_name_index: u16,
+    pub inner_class_access_flags: u16,
+}
+
+impl InnerClass {
+    pub fn new(mut rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let inner_class_info_index = rdr.read_u16::<BigEndian>()?;
+        let outer_class_info_index = rdr.read_u16::<BigEndian>()?;
+        let inner_name_index = rdr.read_u16::<BigEndian>()?;
+        let inner_class_access_flags = rdr.read_u16::<BigEndian>()?;
+
+        Ok((
+            Self {
+                inner_class_info_index,
+                outer_class_info_index,
+                inner_name_index,
+                inner_class_access_flags,
+            },
+            rdr,
+        ))
+    }
+}
+
+impl fmt::Debug for InnerClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "inner_class: #{}, outer_class: #{}, inner_name: #{}, access_flags: {}",
+            self.inner_class_info_index,
+            self.outer_class_info_index,
+            self.inner_name_index,
+            self.inner_class_access_flags
+        )?;
+        Ok(())
+    }
+}
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.7.6
+#[derive(Clone)]
+#[repr(C)]
+pub struct InnerClassesAttribute {
+    number_of_classes: u16,
+    pub classes: Vec<InnerClass>,
+}
+
+impl InnerClassesAttribute {
+    pub fn new(mut rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let number_of_classes = rdr.read_u16::<BigEndian>()?;
+        let (classes, rdr) =
+            (0..number_of_classes).try_fold((Vec::new(), rdr), |(mut ret, rdr), _i| match InnerClass::new(rdr) {
+                Ok((class, rdr2)) => {
+                    ret.push(class);
+                    Ok((ret, rdr2))
+                }
+                Err(err) => Err(err),
+            })?;
+
+        Ok((
+            Self {
+                number_of_classes,
+                classes,
+            },
+            rdr,
+        ))
+    }
+}
+
+impl fmt::Debug for InnerClassesAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for class in &self.classes {
+            writeln!(f, "\t inner_classes: {:?}", class)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::ReaderResult;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.7.28
+#[derive(Clone)]
+#[repr(C)]
+pub struct NestHostAttribute {
+    pub host_class_index: u16,
+}
+
+impl NestHostAttribute {
+    pub fn new(mut rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let host_class_index = rdr.read_u16::<BigEndian>()?;
+        Ok((Self { host_class_index }, rdr))
+    }
+}
+
+impl fmt::Debug for NestHostAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\t nest_host: #{}", self.host_class_index)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::ReaderResult;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.7.31
+#[derive(Clone)]
+#[repr(C)]
+pub struct PermittedSubclassesAttribute {
+    number_of_classes: u16,
+    pub classes: Vec<u16>,
+}
+
+impl PermittedSubclassesAttribute {
+    pub fn new(mut rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let number_of_classes = rdr.read_u16::<BigEndian>()?;
+        let (classes, rdr) = (0..number_of_classes).try_fold((Vec::new(), rdr), |(mut ret, mut rdr), _i| {
+            match rdr.read_u16::<BigEndian>() {
+                Ok(class) => {
+                    ret.push(class);
+                    Ok((ret, rdr))
+                }
+                Err(err) => Err(err),
+            }
+        })?;
+
+        Ok((
+            Self {
+                number_of_classes,
+                classes,
+            },
+            rdr,
+        ))
+    }
+}
+
+impl fmt::Debug for PermittedSubclassesAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for class in &self.classes {
+            writeln!(f, "\t permitted_subclasses: #{}", class)?;
+        }
+        Ok(())
+    }
+}
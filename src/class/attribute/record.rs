@@ -0,0 +1,104 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::attribute::Attribute;
+use crate::class::constant_pool_table::Utf8Table;
+use crate::class::method::NotFoundUtf8;
+use crate::class::ReaderResult;
+
+#[derive(Clone)]
+#[repr(C)]
+pub struct RecordComponent {
+    pub name: String,
+    pub descriptor: String,
+    attributes_count: u16,
+    pub attribute_info: Vec<Attribute>,
+}
+
+impl RecordComponent {
+    pub fn new(mut rdr: Cursor<Vec<u8>>, utf8_table: &Utf8Table) -> ReaderResult<Self> {
+        let name_index = rdr.read_u16::<BigEndian>()?;
+        let name = utf8_table
+            .get(&name_index)
+            .ok_or_else(|| NotFoundUtf8::new(name_index, utf8_table.clone()))?
+            .to_string();
+
+        let descriptor_index = rdr.read_u16::<BigEndian>()?;
+        let descriptor = utf8_table
+            .get(&descriptor_index)
+            .ok_or_else(|| NotFoundUtf8::new(descriptor_index, utf8_table.clone()))?
+            .to_string();
+        let attributes_count = rdr.read_u16::<BigEndian>()?;
+        let (attribute_info, rdr) = (0..attributes_count).try_fold((Vec::new(), rdr), |(mut ret, rdr), _i| {
+            match Attribute::new(rdr, utf8_table) {
+                Ok((ai, rdr2)) => {
+                    ret.push(ai);
+                    Ok((ret, rdr2))
+                }
+                Err(err) => Err(err),
+            }
+        })?;
+
+        Ok((
+            Self {
+                name,
+                descriptor,
+                attributes_count,
+                attribute_info,
+            },
+            rdr,
+        ))
+    }
+}
+
+impl fmt::Debug for RecordComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\t record_component: {} {}", self.name, self.descriptor)?;
+        for ai in &self.attribute_info {
+            write!(f, "{}", ai)?;
+        }
+        Ok(())
+    }
+}
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.7.30
+#[derive(Clone)]
+#[repr(C)]
+pub struct RecordAttribute {
+    components_count: u16,
+    pub components: Vec<RecordComponent>,
+}
+
+impl RecordAttribute {
+    pub fn new(mut rdr: Cursor<Vec<u8>>, utf8_table: &Utf8Table) -> ReaderResult<Self> {
+        let components_count = rdr.read_u16::<BigEndian>()?;
+        let (components, rdr) = (0..components_count).try_fold((Vec::new(), rdr), |(mut ret, rdr), _i| {
+            match RecordComponent::new(rdr, utf8_table) {
+                Ok((component, rdr2)) => {
+                    ret.push(component);
+                    Ok((ret, rdr2))
+                }
+                Err(err) => Err(err),
+            }
+        })?;
+
+        Ok((
+            Self {
+                components_count,
+                components,
+            },
+            rdr,
+        ))
+    }
+}
+
+impl fmt::Debug for RecordAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for component in &self.components {
+            write!(f, "{:?}", component)?;
+        }
+        Ok(())
+    }
+}
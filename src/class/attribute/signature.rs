@@ -0,0 +1,33 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::constant_pool_table::Utf8Table;
+use crate::class::method::NotFoundUtf8;
+use crate::class::ReaderResult;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.7.9
+#[derive(Clone)]
+#[repr(C)]
+pub struct SignatureAttribute {
+    pub signature: String,
+}
+
+impl SignatureAttribute {
+    pub fn new(mut rdr: Cursor<Vec<u8>>, utf8_table: &Utf8Table) -> ReaderResult<Self> {
+        let signature_index = rdr.read_u16::<BigEndian>()?;
+        let signature = utf8_table
+            .get(&signature_index)
+            .ok_or_else(|| NotFoundUtf8::new(signature_index, utf8_table.clone()))?
+            .to_string();
+        Ok((Self { signature }, rdr))
+    }
+}
+
+impl fmt::Debug for SignatureAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\t signature: {}", self.signature)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::constant_pool_table::Utf8Table;
+use crate::class::method::NotFoundUtf8;
+use crate::class::ReaderResult;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.7.10
+#[derive(Clone)]
+#[repr(C)]
+pub struct SourceFileAttribute {
+    pub sourcefile: String,
+}
+
+impl SourceFileAttribute {
+    pub fn new(mut rdr: Cursor<Vec<u8>>, utf8_table: &Utf8Table) -> ReaderResult<Self> {
+        let sourcefile_index = rdr.read_u16::<BigEndian>()?;
+        let sourcefile = utf8_table
+            .get(&sourcefile_index)
+            .ok_or_else(|| NotFoundUtf8::new(sourcefile_index, utf8_table.clone()))?
+            .to_string();
+        Ok((Self { sourcefile }, rdr))
+    }
+}
+
+impl fmt::Debug for SourceFileAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\t source_file: {}", self.sourcefile)?;
+        Ok(())
+    }
+}
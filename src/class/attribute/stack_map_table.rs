@@ -0,0 +1,38 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::attribute::read_bytes;
+use crate::class::ReaderResult;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.7.4
+// The frames are only needed by a type checking verifier, so they are kept undecoded.
+#[derive(Clone)]
+#[repr(C)]
+pub struct StackMapTableAttribute {
+    number_of_entries: u16,
+    entries: Vec<u8>,
+}
+
+impl StackMapTableAttribute {
+    pub fn new(mut rdr: Cursor<Vec<u8>>, attribute_length: u32) -> ReaderResult<Self> {
+        let number_of_entries = rdr.read_u16::<BigEndian>()?;
+        let entries = read_bytes(&mut rdr, attribute_length.saturating_sub(2))?;
+
+        Ok((
+            Self {
+                number_of_entries,
+                entries,
+            },
+            rdr,
+        ))
+    }
+}
+
+impl fmt::Debug for StackMapTableAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\t stack_map_table: {} entries", self.number_of_entries)?;
+        Ok(())
+    }
+}
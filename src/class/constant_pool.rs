@@ -1,8 +1,18 @@
 mod class_info;
+mod double_info;
+mod dynamic_info;
 mod fieldref_info;
+mod float_info;
+mod integer_info;
 mod interface_methodref_info;
+mod invoke_dynamic_info;
+mod long_info;
+mod method_handle_info;
+mod method_type_info;
 mod methodref_info;
+mod module_info;
 mod name_and_type_info;
+mod package_info;
 mod string_info;
 pub mod tag;
 pub mod utf8_info;
@@ -15,10 +25,20 @@ use num_traits::FromPrimitive;
 
 use crate::class::ReaderResult;
 use class_info::ClassInfo;
+use double_info::DoubleInfo;
+use dynamic_info::DynamicInfo;
 use fieldref_info::FieldrefInfo;
+use float_info::FloatInfo;
+use integer_info::IntegerInfo;
 use interface_methodref_info::InterfaceMethodrefInfo;
+use invoke_dynamic_info::InvokeDynamicInfo;
+use long_info::LongInfo;
+use method_handle_info::MethodHandleInfo;
+use method_type_info::MethodTypeInfo;
 use methodref_info::MethodrefInfo;
+use module_info::ModuleInfo;
 use name_and_type_info::NameAndTypeInfo;
+use package_info::PackageInfo;
 use string_info::StringInfo;
 use tag::{ConstantTag, NotFoundConstantTagError};
 use utf8_info::Utf8Info;
@@ -54,20 +74,40 @@ pub enum ConstantPoolInfo {
     MethodrefInfo(MethodrefInfo),
     InterfaceMethodrefInfo(InterfaceMethodrefInfo),
     StringInfo(StringInfo),
+    IntegerInfo(IntegerInfo),
+    FloatInfo(FloatInfo),
+    LongInfo(LongInfo),
+    DoubleInfo(DoubleInfo),
     NameAndTypeInfo(NameAndTypeInfo),
     Utf8Info(Utf8Info),
+    MethodHandleInfo(MethodHandleInfo),
+    MethodTypeInfo(MethodTypeInfo),
+    DynamicInfo(DynamicInfo),
+    InvokeDynamicInfo(InvokeDynamicInfo),
+    ModuleInfo(ModuleInfo),
+    PackageInfo(PackageInfo),
 }
 
 impl fmt::Display for ConstantPoolInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &*self {
+        match self {
             Self::ClassInfo(info) => write!(f, "{}", info)?,
             Self::FieldrefInfo(info) => write!(f, "{}", info)?,
             Self::MethodrefInfo(info) => write!(f, "{}", info)?,
             Self::InterfaceMethodrefInfo(info) => write!(f, "{}", info)?,
             Self::StringInfo(info) => write!(f, "{}", info)?,
+            Self::IntegerInfo(info) => write!(f, "{}", info)?,
+            Self::FloatInfo(info) => write!(f, "{}", info)?,
+            Self::LongInfo(info) => write!(f, "{}", info)?,
+            Self::DoubleInfo(info) => write!(f, "{}", info)?,
             Self::NameAndTypeInfo(info) => write!(f, "{}", info)?,
             Self::Utf8Info(info) => write!(f, "{}", info)?,
+            Self::MethodHandleInfo(info) => write!(f, "{}", info)?,
+            Self::MethodTypeInfo(info) => write!(f, "{}", info)?,
+            Self::DynamicInfo(info) => write!(f, "{}", info)?,
+            Self::InvokeDynamicInfo(info) => write!(f, "{}", info)?,
+            Self::ModuleInfo(info) => write!(f, "{}", info)?,
+            Self::PackageInfo(info) => write!(f, "{}", info)?,
         };
         Ok(())
     }
@@ -96,6 +136,22 @@ impl ConstantPoolInfo {
                 let (info, rdr) = StringInfo::new(rdr)?;
                 (Self::StringInfo(info), rdr)
             }
+            ConstantTag::Integer => {
+                let (info, rdr) = IntegerInfo::new(rdr)?;
+                (Self::IntegerInfo(info), rdr)
+            }
+            ConstantTag::Float => {
+                let (info, rdr) = FloatInfo::new(rdr)?;
+                (Self::FloatInfo(info), rdr)
+            }
+            ConstantTag::Long => {
+                let (info, rdr) = LongInfo::new(rdr)?;
+                (Self::LongInfo(info), rdr)
+            }
+            ConstantTag::Double => {
+                let (info, rdr) = DoubleInfo::new(rdr)?;
+                (Self::DoubleInfo(info), rdr)
+            }
             ConstantTag::NameAndType => {
                 let (info, rdr) = NameAndTypeInfo::new(rdr)?;
                 (Self::NameAndTypeInfo(info), rdr)
@@ -104,7 +160,30 @@ impl ConstantPoolInfo {
                 let (info, rdr) = Utf8Info::new(rdr)?;
                 (Self::Utf8Info(info), rdr)
             }
-            _ => unimplemented!("Sorry..."),
+            ConstantTag::MethodHandle => {
+                let (info, rdr) = MethodHandleInfo::new(rdr)?;
+                (Self::MethodHandleInfo(info), rdr)
+            }
+            ConstantTag::MethodType => {
+                let (info, rdr) = MethodTypeInfo::new(rdr)?;
+                (Self::MethodTypeInfo(info), rdr)
+            }
+            ConstantTag::Dynamic => {
+                let (info, rdr) = DynamicInfo::new(rdr)?;
+                (Self::DynamicInfo(info), rdr)
+            }
+            ConstantTag::InvokeDynamic => {
+                let (info, rdr) = InvokeDynamicInfo::new(rdr)?;
+                (Self::InvokeDynamicInfo(info), rdr)
+            }
+            ConstantTag::Module => {
+                let (info, rdr) = ModuleInfo::new(rdr)?;
+                (Self::ModuleInfo(info), rdr)
+            }
+            ConstantTag::Package => {
+                let (info, rdr) = PackageInfo::new(rdr)?;
+                (Self::PackageInfo(info), rdr)
+            }
         })
     }
 }
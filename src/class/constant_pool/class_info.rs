@@ -9,7 +9,7 @@ use crate::class::ReaderResult;
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct ClassInfo {
-    name_index: u16,
+    pub name_index: u16,
 }
 
 impl ClassInfo {
@@ -0,0 +1,33 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::ReaderResult;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.4.5
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct DoubleInfo {
+    high_bytes: u32,
+    low_bytes: u32,
+}
+
+impl DoubleInfo {
+    pub fn new(mut rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let high_bytes = rdr.read_u32::<BigEndian>()?;
+        let low_bytes = rdr.read_u32::<BigEndian>()?;
+        Ok((Self { high_bytes, low_bytes }, rdr))
+    }
+
+    pub fn value(&self) -> f64 {
+        f64::from_bits(u64::from(self.high_bytes) << 32 | u64::from(self.low_bytes))
+    }
+}
+
+impl fmt::Display for DoubleInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Double   \t {}d", self.value())?;
+        Ok(())
+    }
+}
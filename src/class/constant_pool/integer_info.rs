@@ -0,0 +1,31 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::ReaderResult;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.4.4
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct IntegerInfo {
+    bytes: u32,
+}
+
+impl IntegerInfo {
+    pub fn new(mut rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let bytes = rdr.read_u32::<BigEndian>()?;
+        Ok((Self { bytes }, rdr))
+    }
+
+    pub fn value(&self) -> i32 {
+        self.bytes as i32
+    }
+}
+
+impl fmt::Display for IntegerInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Integer  \t {}", self.value())?;
+        Ok(())
+    }
+}
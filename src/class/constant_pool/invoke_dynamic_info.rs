@@ -0,0 +1,39 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::ReaderResult;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.4.10
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct InvokeDynamicInfo {
+    pub bootstrap_method_attr_index: u16,
+    pub name_and_type_index: u16,
+}
+
+impl InvokeDynamicInfo {
+    pub fn new(mut rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let bootstrap_method_attr_index = rdr.read_u16::<BigEndian>()?;
+        let name_and_type_index = rdr.read_u16::<BigEndian>()?;
+        Ok((
+            Self {
+                bootstrap_method_attr_index,
+                name_and_type_index,
+            },
+            rdr,
+        ))
+    }
+}
+
+impl fmt::Display for InvokeDynamicInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "InvokeDynamic\t #{}:#{}",
+            self.bootstrap_method_attr_index, self.name_and_type_index
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::ReaderResult;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.4.8
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct MethodHandleInfo {
+    pub reference_kind: u8,
+    pub reference_index: u16,
+}
+
+impl MethodHandleInfo {
+    pub fn new(mut rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let reference_kind = rdr.read_u8()?;
+        let reference_index = rdr.read_u16::<BigEndian>()?;
+        Ok((
+            Self {
+                reference_kind,
+                reference_index,
+            },
+            rdr,
+        ))
+    }
+
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-5.html#jvms-5.4.3.5
+    pub fn reference_kind_name(&self) -> &'static str {
+        match self.reference_kind {
+            1 => "REF_getField",
+            2 => "REF_getStatic",
+            3 => "REF_putField",
+            4 => "REF_putStatic",
+            5 => "REF_invokeVirtual",
+            6 => "REF_invokeStatic",
+            7 => "REF_invokeSpecial",
+            8 => "REF_newInvokeSpecial",
+            9 => "REF_invokeInterface",
+            _ => "REF_unknown",
+        }
+    }
+}
+
+impl fmt::Display for MethodHandleInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "MethodHandle\t {}:#{}",
+            self.reference_kind_name(),
+            self.reference_index
+        )?;
+        Ok(())
+    }
+}
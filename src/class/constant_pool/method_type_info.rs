@@ -0,0 +1,27 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::ReaderResult;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.4.9
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct MethodTypeInfo {
+    pub descriptor_index: u16,
+}
+
+impl MethodTypeInfo {
+    pub fn new(mut rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let descriptor_index = rdr.read_u16::<BigEndian>()?;
+        Ok((Self { descriptor_index }, rdr))
+    }
+}
+
+impl fmt::Display for MethodTypeInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MethodType\t #{}", self.descriptor_index)?;
+        Ok(())
+    }
+}
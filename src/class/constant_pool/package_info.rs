@@ -0,0 +1,27 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::ReaderResult;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.4.12
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PackageInfo {
+    pub name_index: u16,
+}
+
+impl PackageInfo {
+    pub fn new(mut rdr: Cursor<Vec<u8>>) -> ReaderResult<Self> {
+        let name_index = rdr.read_u16::<BigEndian>()?;
+        Ok((Self { name_index }, rdr))
+    }
+}
+
+impl fmt::Display for PackageInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Package \t #{}", self.name_index)?;
+        Ok(())
+    }
+}
@@ -22,7 +22,7 @@ impl fmt::Display for NotFoundConstantTagError {
     }
 }
 
-// https://docs.oracle.com/javase/specs/jvms/se7/html/jvms-4.html#jvms-4.4
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.4
 #[derive(Clone, Copy, FromPrimitive, PartialEq)]
 pub enum ConstantTag {
     Class = 7,
@@ -38,7 +38,10 @@ pub enum ConstantTag {
     Utf8 = 1,
     MethodHandle = 15,
     MethodType = 16,
+    Dynamic = 17,
     InvokeDynamic = 18,
+    Module = 19,
+    Package = 20,
 }
 
 impl fmt::Display for ConstantTag {
@@ -57,7 +60,10 @@ impl fmt::Display for ConstantTag {
             Self::Utf8 => writeln!(f, "Utf8")?,
             Self::MethodHandle => writeln!(f, "MethodHandle")?,
             Self::MethodType => writeln!(f, "MethodType")?,
+            Self::Dynamic => writeln!(f, "Dynamic")?,
             Self::InvokeDynamic => writeln!(f, "InvokeDynamic")?,
+            Self::Module => writeln!(f, "Module")?,
+            Self::Package => writeln!(f, "Package")?,
         }
         Ok(())
     }
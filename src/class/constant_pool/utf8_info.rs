@@ -5,12 +5,34 @@ use byteorder::{BigEndian, ReadBytesExt};
 
 use crate::class::ReaderResult;
 
+#[derive(Debug)]
+pub struct ModifiedUtf8Error {
+    index: usize,
+}
+
+impl ModifiedUtf8Error {
+    pub fn new(index: usize) -> Self {
+        Self { index }
+    }
+}
+
+impl std::error::Error for ModifiedUtf8Error {}
+
+impl fmt::Display for ModifiedUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Illegal modified UTF-8 byte at {} of a Utf8 constant", self.index)?;
+        Ok(())
+    }
+}
+
 // https://docs.oracle.com/javase/specs/jvms/se7/html/jvms-4.html#jvms-4.4.7
 #[repr(C)]
 #[derive(Clone)]
 pub struct Utf8Info {
     length: u16,
     pub bytes: Vec<u8>,
+    // The bytes decoded when the constant is read, so that a malformed one fails the class loading.
+    string: String,
 }
 
 impl Utf8Info {
@@ -23,22 +45,51 @@ impl Utf8Info {
             }
             Err(err) => Err(err),
         })?;
-        Ok((Self { length, bytes }, rdr))
+        let string = decode_modified_utf8(&bytes)?;
+        Ok((Self { length, bytes, string }, rdr))
+    }
+}
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.4.7
+// Modified UTF-8 encodes the UTF-16 code units of a Java string: NUL takes the two bytes `C0 80`, and
+// a supplementary character takes the three bytes of each of its surrogates. A Rust string cannot
+// hold a lone surrogate, so it becomes U+FFFD.
+fn decode_modified_utf8(bytes: &[u8]) -> Result<String, ModifiedUtf8Error> {
+    let mut units = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let continuation = |offset: usize| match bytes.get(i + offset) {
+            Some(byte) if byte & 0xc0 == 0x80 => Ok(u16::from(byte & 0x3f)),
+            _ => Err(ModifiedUtf8Error::new(i + offset)),
+        };
+        let x = bytes[i];
+        let (unit, len) = match x {
+            0x01..=0x7f => (u16::from(x), 1),
+            0xc0..=0xdf => ((u16::from(x & 0x1f) << 6) | continuation(1)?, 2),
+            0xe0..=0xef => (
+                (u16::from(x & 0x0f) << 12) | (continuation(1)? << 6) | continuation(2)?,
+                3,
+            ),
+            _ => return Err(ModifiedUtf8Error::new(i)),
+        };
+        units.push(unit);
+        i += len;
     }
+    Ok(std::char::decode_utf16(units)
+        .map(|c| c.unwrap_or(std::char::REPLACEMENT_CHARACTER))
+        .collect())
 }
 
 impl fmt::Display for Utf8Info {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let string = std::str::from_utf8(&self.bytes).unwrap();
-        write!(f, "{}", string)?;
+        write!(f, "{}", self.string)?;
         Ok(())
     }
 }
 
 impl fmt::Debug for Utf8Info {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let string = std::str::from_utf8(&self.bytes).unwrap();
-        write!(f, "{}", string)?;
+        write!(f, "{}", self.string)?;
         Ok(())
     }
 }
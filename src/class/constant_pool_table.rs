@@ -1,13 +1,36 @@
 use std::collections::HashMap;
-use std::slice::Iter;
+use std::fmt;
 
+use crate::class::constant_pool::tag::ConstantTag;
 use crate::class::constant_pool::utf8_info::Utf8Info;
 use crate::class::constant_pool::{ConstantPool, ConstantPoolInfo};
 
 pub type Utf8Table = HashMap<u16, Utf8Info>;
 
+#[derive(Debug)]
+pub struct NotFoundClassInfo {
+    index: u16,
+}
+
+impl NotFoundClassInfo {
+    pub fn new(index: u16) -> Self {
+        Self { index }
+    }
+}
+
+impl std::error::Error for NotFoundClassInfo {}
+
+impl fmt::Display for NotFoundClassInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Constant pool #{} is not a Class", self.index)?;
+        Ok(())
+    }
+}
+
+// Entries are kept at `constant pool index - 1`. The slot following a Long or Double is
+// unusable (JVMS 4.4.5) and is held as `None` so that the indexes stay aligned.
 pub struct ConstantPoolTable {
-    pub table: Vec<ConstantPool>,
+    pub table: Vec<Option<ConstantPool>>,
 }
 
 impl ConstantPoolTable {
@@ -15,27 +38,69 @@ impl ConstantPoolTable {
         ConstantPoolTable { table: Vec::new() }
     }
 
-    pub fn iter(&self) -> Iter<ConstantPool> {
-        self.table.iter()
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    // Iterates over the usable entries together with their constant pool index.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, &ConstantPool)> {
+        self.table
+            .iter()
+            .enumerate()
+            .flat_map(|(i, cp)| cp.as_ref().map(|cp| (i as u16 + 1, cp)))
     }
 
     pub fn push(&mut self, item: ConstantPool) {
-        self.table.push(item)
+        let two_slots = matches!(item.tag, ConstantTag::Long | ConstantTag::Double);
+        self.table.push(Some(item));
+        if two_slots {
+            self.table.push(None);
+        }
+    }
+
+    pub fn get(&self, index: u16) -> Option<&ConstantPool> {
+        self.table.get(usize::from(index).checked_sub(1)?)?.as_ref()
     }
 
-    pub fn get(&self, index: usize) -> Option<&ConstantPool> {
-        self.table.get(index)
+    // Resolves a CONSTANT_Class_info entry to its binary name such as `java/lang/Object`.
+    pub fn class_name(&self, index: u16) -> Option<String> {
+        let name_index = match &self.get(index)?.info {
+            ConstantPoolInfo::ClassInfo(class_info) => class_info.name_index,
+            _ => return None,
+        };
+        self.utf8(name_index)
+    }
+
+    pub fn utf8(&self, index: u16) -> Option<String> {
+        match &self.get(index)?.info {
+            ConstantPoolInfo::Utf8Info(utf8info) => Some(utf8info.to_string()),
+            _ => None,
+        }
+    }
+
+    // Resolves a CONSTANT_NameAndType_info entry to its name and its descriptor.
+    pub fn name_and_type(&self, index: u16) -> Option<(String, String)> {
+        match &self.get(index)?.info {
+            ConstantPoolInfo::NameAndTypeInfo(name_and_type) => Some((
+                self.utf8(name_and_type.name_index)?,
+                self.utf8(name_and_type.descriptor_index)?,
+            )),
+            _ => None,
+        }
     }
 
     pub fn utf8info(&self) -> Utf8Table {
         self.iter()
-            .enumerate()
             .flat_map(|(i, cp)| match &cp.info {
-                ConstantPoolInfo::Utf8Info(utf8info) => Some((i + 1, utf8info)),
+                ConstantPoolInfo::Utf8Info(utf8info) => Some((i, utf8info)),
                 _ => None,
             })
             .fold(Utf8Table::new(), |mut hash, (index, utf8info)| {
-                hash.insert(index as u16, utf8info.clone());
+                hash.insert(index, utf8info.clone());
                 hash
             })
     }
@@ -43,9 +108,9 @@ impl ConstantPoolTable {
 
 impl IntoIterator for ConstantPoolTable {
     type Item = ConstantPool;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = std::iter::Flatten<std::vec::IntoIter<Option<Self::Item>>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.table.into_iter()
+        self.table.into_iter().flatten()
     }
 }
@@ -0,0 +1,148 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub struct DescriptorError {
+    descriptor: String,
+}
+
+impl DescriptorError {
+    pub fn new(descriptor: &str) -> Self {
+        Self {
+            descriptor: descriptor.to_string(),
+        }
+    }
+}
+
+impl std::error::Error for DescriptorError {}
+
+impl fmt::Display for DescriptorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid descriptor: {}", self.descriptor)?;
+        Ok(())
+    }
+}
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.3.2
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldType {
+    Byte,
+    Char,
+    Double,
+    Float,
+    Int,
+    Long,
+    Short,
+    Boolean,
+    // The binary name of the class in internal form, e.g. `java/lang/String`.
+    Object(String),
+    Array(Box<FieldType>),
+}
+
+impl FieldType {
+    pub fn new(descriptor: &str) -> Result<Self, DescriptorError> {
+        match Self::parse(descriptor) {
+            Some((field_type, "")) => Ok(field_type),
+            _ => Err(DescriptorError::new(descriptor)),
+        }
+    }
+
+    // Parses a field type at the beginning of `descriptor` and returns it with the rest.
+    fn parse(descriptor: &str) -> Option<(Self, &str)> {
+        let mut chars = descriptor.chars();
+        let field_type = match chars.next()? {
+            'B' => Self::Byte,
+            'C' => Self::Char,
+            'D' => Self::Double,
+            'F' => Self::Float,
+            'I' => Self::Int,
+            'J' => Self::Long,
+            'S' => Self::Short,
+            'Z' => Self::Boolean,
+            'L' => {
+                let (class_name, rest) = chars.as_str().split_once(';')?;
+                if class_name.is_empty() {
+                    return None;
+                }
+                return Some((Self::Object(class_name.to_string()), rest));
+            }
+            '[' => {
+                let (component, rest) = Self::parse(chars.as_str())?;
+                return Some((Self::Array(Box::new(component)), rest));
+            }
+            _ => return None,
+        };
+        Some((field_type, chars.as_str()))
+    }
+
+    // long and double take two local variable slots and count as two on the operand stack.
+    pub fn slots(&self) -> usize {
+        match self {
+            Self::Long | Self::Double => 2,
+            _ => 1,
+        }
+    }
+}
+
+impl fmt::Display for FieldType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Byte => write!(f, "byte")?,
+            Self::Char => write!(f, "char")?,
+            Self::Double => write!(f, "double")?,
+            Self::Float => write!(f, "float")?,
+            Self::Int => write!(f, "int")?,
+            Self::Long => write!(f, "long")?,
+            Self::Short => write!(f, "short")?,
+            Self::Boolean => write!(f, "boolean")?,
+            Self::Object(class_name) => write!(f, "{}", class_name.replace('/', "."))?,
+            Self::Array(component) => write!(f, "{}[]", component)?,
+        }
+        Ok(())
+    }
+}
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.3.3
+#[derive(Debug, Clone, PartialEq)]
+pub struct MethodDescriptor {
+    pub parameters: Vec<FieldType>,
+    // `None` means void.
+    pub return_type: Option<FieldType>,
+}
+
+impl MethodDescriptor {
+    pub fn new(descriptor: &str) -> Result<Self, DescriptorError> {
+        let err = || DescriptorError::new(descriptor);
+        let mut rest = descriptor.strip_prefix('(').ok_or_else(err)?;
+        let mut parameters = vec![];
+        while !rest.starts_with(')') {
+            let (parameter, next) = FieldType::parse(rest).ok_or_else(err)?;
+            parameters.push(parameter);
+            rest = next;
+        }
+        let return_type = match &rest[1..] {
+            "V" => None,
+            return_type => Some(FieldType::new(return_type).map_err(|_| err())?),
+        };
+        Ok(Self {
+            parameters,
+            return_type,
+        })
+    }
+
+    // The number of local variable slots the arguments take, not including `this`.
+    pub fn parameter_slots(&self) -> usize {
+        self.parameters.iter().map(|parameter| parameter.slots()).sum()
+    }
+}
+
+impl fmt::Display for MethodDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.return_type {
+            Some(return_type) => write!(f, "{}", return_type)?,
+            None => write!(f, "void")?,
+        }
+        let parameters: Vec<String> = self.parameters.iter().map(|parameter| parameter.to_string()).collect();
+        write!(f, " ({})", parameters.join(", "))?;
+        Ok(())
+    }
+}
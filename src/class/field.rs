@@ -0,0 +1,105 @@
+use std::fmt;
+use std::io::Cursor;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+use crate::class::attribute::constant_value::ConstantValueAttribute;
+use crate::class::attribute::Attribute;
+use crate::class::constant_pool_table::Utf8Table;
+use crate::class::descriptor::FieldType;
+use crate::class::method::NotFoundUtf8;
+use crate::class::signature::{FieldSignature, SignatureError};
+use crate::class::ReaderResult;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.5
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct FieldInfo {
+    pub access_flags: u16,
+    pub name: String,
+    pub descriptor: String,
+    pub field_type: FieldType,
+    attributes_count: u16,
+    pub attribute_info: Vec<Attribute>,
+}
+
+impl FieldInfo {
+    pub fn new(mut rdr: Cursor<Vec<u8>>, utf8_table: &Utf8Table) -> ReaderResult<Self> {
+        let access_flags = rdr.read_u16::<BigEndian>()?;
+
+        let name_index = rdr.read_u16::<BigEndian>()?;
+        let name = utf8_table
+            .get(&name_index)
+            .ok_or_else(|| NotFoundUtf8::new(name_index, utf8_table.clone()))?
+            .to_string();
+
+        let descriptor_index = rdr.read_u16::<BigEndian>()?;
+        let descriptor = utf8_table
+            .get(&descriptor_index)
+            .ok_or_else(|| NotFoundUtf8::new(descriptor_index, utf8_table.clone()))?
+            .to_string();
+        let field_type = FieldType::new(&descriptor)?;
+        let attributes_count = rdr.read_u16::<BigEndian>()?;
+        let (attribute_info, rdr) = (0..attributes_count).try_fold((Vec::new(), rdr), |(mut ret, rdr), _i| {
+            match Attribute::new(rdr, utf8_table) {
+                Ok((ai, rdr2)) => {
+                    ret.push(ai);
+                    Ok((ret, rdr2))
+                }
+                Err(err) => Err(err),
+            }
+        })?;
+
+        Ok((
+            Self {
+                access_flags,
+                name,
+                descriptor,
+                field_type,
+                attributes_count,
+                attribute_info,
+            },
+            rdr,
+        ))
+    }
+
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.5-200-A.1
+    pub fn is_static(&self) -> bool {
+        self.access_flags & 0x0008 != 0
+    }
+
+    pub fn constant_value(&self) -> Option<&ConstantValueAttribute> {
+        self.attribute_info.iter().find_map(|attr| match attr {
+            Attribute::ConstantValue(constant_value) => Some(constant_value),
+            _ => None,
+        })
+    }
+
+    // The generic type from the Signature attribute, if the field has one.
+    pub fn signature(&self) -> Option<Result<FieldSignature, SignatureError>> {
+        self.attribute_info.iter().find_map(|attr| match attr {
+            Attribute::Signature(signature) => Some(FieldSignature::new(&signature.signature)),
+            _ => None,
+        })
+    }
+}
+
+impl fmt::Display for FieldInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "\t name:             {}", self.name)?;
+        writeln!(f, "\t access_flags:     {}", self.access_flags)?;
+        writeln!(f, "\t descriptor:       {}", self.descriptor)?;
+        writeln!(f, "\t type:             {}", self.field_type)?;
+        if let Some(signature) = self.signature() {
+            match signature {
+                Ok(signature) => writeln!(f, "\t generic type:     {}", signature)?,
+                Err(err) => writeln!(f, "\t generic type:     {}", err)?,
+            }
+        }
+        writeln!(f, "\t attributes_count: {}", self.attributes_count)?;
+        for ai in &self.attribute_info {
+            write!(f, "{}", ai)?;
+        }
+        Ok(())
+    }
+}
@@ -6,6 +6,8 @@ use byteorder::{BigEndian, ReadBytesExt};
 use crate::class::attribute::code::CodeAttribute;
 use crate::class::attribute::Attribute;
 use crate::class::constant_pool_table::Utf8Table;
+use crate::class::descriptor::MethodDescriptor;
+use crate::class::signature::{MethodSignature, SignatureError};
 use crate::class::ReaderResult;
 
 #[derive(Debug)]
@@ -34,11 +36,12 @@ impl fmt::Display for NotFoundUtf8 {
 #[repr(C)]
 #[derive(Debug, Clone)]
 pub struct MethodInfo {
-    access_flags: u16,
+    pub access_flags: u16,
     pub name: String,
-    descriptor: String,
+    pub descriptor: String,
+    pub method_descriptor: MethodDescriptor,
     attributes_count: u16,
-    attribute_info: Vec<Attribute>,
+    pub attribute_info: Vec<Attribute>,
 }
 
 impl MethodInfo {
@@ -56,9 +59,10 @@ impl MethodInfo {
             .get(&descriptor_index)
             .ok_or_else(|| NotFoundUtf8::new(descriptor_index, utf8_table.clone()))?
             .to_string();
+        let method_descriptor = MethodDescriptor::new(&descriptor)?;
         let attributes_count = rdr.read_u16::<BigEndian>()?;
         let (attribute_info, rdr) = (0..attributes_count).try_fold((Vec::new(), rdr), |(mut ret, rdr), _i| {
-            match Attribute::new(rdr, &utf8_table) {
+            match Attribute::new(rdr, utf8_table) {
                 Ok((ai, rdr2)) => {
                     ret.push(ai);
                     Ok((ret, rdr2))
@@ -72,6 +76,7 @@ impl MethodInfo {
                 access_flags,
                 name,
                 descriptor,
+                method_descriptor,
                 attributes_count,
                 attribute_info,
             },
@@ -79,6 +84,23 @@ impl MethodInfo {
         ))
     }
 
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.6-200-A.1
+    pub fn is_public(&self) -> bool {
+        self.access_flags & 0x0001 != 0
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.access_flags & 0x0002 != 0
+    }
+
+    pub fn is_static(&self) -> bool {
+        self.access_flags & 0x0008 != 0
+    }
+
+    pub fn is_abstract(&self) -> bool {
+        self.access_flags & 0x0400 != 0
+    }
+
     pub fn code_attribute(&self) -> Vec<&CodeAttribute> {
         self.attribute_info
             .iter()
@@ -88,6 +110,14 @@ impl MethodInfo {
             })
             .collect()
     }
+
+    // The generic type from the Signature attribute, if the method has one.
+    pub fn signature(&self) -> Option<Result<MethodSignature, SignatureError>> {
+        self.attribute_info.iter().find_map(|attr| match attr {
+            Attribute::Signature(signature) => Some(MethodSignature::new(&signature.signature)),
+            _ => None,
+        })
+    }
 }
 
 impl fmt::Display for MethodInfo {
@@ -95,6 +125,13 @@ impl fmt::Display for MethodInfo {
         writeln!(f, "\t name:             {}", self.name)?;
         writeln!(f, "\t access_flags:     {}", self.access_flags)?;
         writeln!(f, "\t descriptor:       {}", self.descriptor)?;
+        writeln!(f, "\t type:             {}", self.method_descriptor)?;
+        if let Some(signature) = self.signature() {
+            match signature {
+                Ok(signature) => writeln!(f, "\t generic type:     {}", signature)?,
+                Err(err) => writeln!(f, "\t generic type:     {}", err)?,
+            }
+        }
         writeln!(f, "\t attributes_count: {}", self.attributes_count)?;
         for ai in &self.attribute_info {
             write!(f, "{}", ai)?;
@@ -0,0 +1,439 @@
+use std::fmt;
+
+use crate::class::descriptor::FieldType;
+
+#[derive(Debug)]
+pub struct SignatureError {
+    signature: String,
+    position: usize,
+}
+
+impl SignatureError {
+    pub fn new(signature: &str, position: usize) -> Self {
+        Self {
+            signature: signature.to_string(),
+            position,
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid signature at {}: {}", self.position, self.signature)?;
+        Ok(())
+    }
+}
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-4.html#jvms-4.7.9.1
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassSignature {
+    pub type_parameters: Vec<TypeParameter>,
+    pub super_class: ClassTypeSignature,
+    pub interfaces: Vec<ClassTypeSignature>,
+}
+
+impl ClassSignature {
+    pub fn new(signature: &str) -> Result<Self, SignatureError> {
+        let mut parser = Parser::new(signature);
+        let type_parameters = parser.type_parameters()?;
+        let super_class = parser.class_type()?;
+        let mut interfaces = vec![];
+        while !parser.is_end() {
+            interfaces.push(parser.class_type()?);
+        }
+        Ok(Self {
+            type_parameters,
+            super_class,
+            interfaces,
+        })
+    }
+}
+
+impl fmt::Display for ClassSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_type_parameters(f, &self.type_parameters)?;
+        write!(f, "extends {}", self.super_class)?;
+        if !self.interfaces.is_empty() {
+            write!(f, " implements {}", join(&self.interfaces))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MethodSignature {
+    pub type_parameters: Vec<TypeParameter>,
+    pub parameters: Vec<JavaTypeSignature>,
+    // `None` means void.
+    pub result: Option<JavaTypeSignature>,
+    pub throws: Vec<ReferenceTypeSignature>,
+}
+
+impl MethodSignature {
+    pub fn new(signature: &str) -> Result<Self, SignatureError> {
+        let mut parser = Parser::new(signature);
+        let type_parameters = parser.type_parameters()?;
+        parser.expect('(')?;
+        let mut parameters = vec![];
+        while !parser.eat(')') {
+            parameters.push(parser.java_type()?);
+        }
+        let result = if parser.eat('V') {
+            None
+        } else {
+            Some(parser.java_type()?)
+        };
+        let mut throws = vec![];
+        while parser.eat('^') {
+            throws.push(parser.reference_type()?);
+        }
+        parser.expect_end()?;
+        Ok(Self {
+            type_parameters,
+            parameters,
+            result,
+            throws,
+        })
+    }
+}
+
+impl fmt::Display for MethodSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_type_parameters(f, &self.type_parameters)?;
+        match &self.result {
+            Some(result) => write!(f, "{}", result)?,
+            None => write!(f, "void")?,
+        }
+        write!(f, " ({})", join(&self.parameters))?;
+        if !self.throws.is_empty() {
+            write!(f, " throws {}", join(&self.throws))?;
+        }
+        Ok(())
+    }
+}
+
+// A field signature is a reference type; primitive fields never have one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldSignature {
+    pub field_type: ReferenceTypeSignature,
+}
+
+impl FieldSignature {
+    pub fn new(signature: &str) -> Result<Self, SignatureError> {
+        let mut parser = Parser::new(signature);
+        let field_type = parser.reference_type()?;
+        parser.expect_end()?;
+        Ok(Self { field_type })
+    }
+}
+
+impl fmt::Display for FieldSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.field_type)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeParameter {
+    pub name: String,
+    pub class_bound: Option<ReferenceTypeSignature>,
+    pub interface_bounds: Vec<ReferenceTypeSignature>,
+}
+
+impl fmt::Display for TypeParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        let bounds: Vec<&ReferenceTypeSignature> =
+            self.class_bound.iter().chain(self.interface_bounds.iter()).collect();
+        if !bounds.is_empty() {
+            write!(f, " extends {}", join_with(&bounds, " & "))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JavaTypeSignature {
+    // One of the primitive variants of `FieldType`.
+    Base(FieldType),
+    Reference(ReferenceTypeSignature),
+}
+
+impl fmt::Display for JavaTypeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Base(field_type) => write!(f, "{}", field_type)?,
+            Self::Reference(reference) => write!(f, "{}", reference)?,
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReferenceTypeSignature {
+    Class(ClassTypeSignature),
+    TypeVariable(String),
+    Array(Box<JavaTypeSignature>),
+}
+
+impl fmt::Display for ReferenceTypeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Class(class_type) => write!(f, "{}", class_type)?,
+            Self::TypeVariable(name) => write!(f, "{}", name)?,
+            Self::Array(component) => write!(f, "{}[]", component)?,
+        }
+        Ok(())
+    }
+}
+
+// e.g. `Ljava/util/Map$Entry<TK;TV;>;` or `Lp/Outer<TT;>.Inner;`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassTypeSignature {
+    // The package in internal form with a trailing slash, e.g. `java/util/`.
+    pub package: String,
+    // The outermost class first, followed by the inner classes it is qualified with.
+    pub classes: Vec<SimpleClassTypeSignature>,
+}
+
+impl fmt::Display for ClassTypeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.package.replace('/', "."), join_with(&self.classes, "."))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimpleClassTypeSignature {
+    pub name: String,
+    pub type_arguments: Vec<TypeArgument>,
+}
+
+impl fmt::Display for SimpleClassTypeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.type_arguments.is_empty() {
+            write!(f, "<{}>", join(&self.type_arguments))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeArgument {
+    // `*`, i.e. `?`
+    Unbounded,
+    Exact(ReferenceTypeSignature),
+    // `+`, i.e. `? extends`
+    Extends(ReferenceTypeSignature),
+    // `-`, i.e. `? super`
+    Super(ReferenceTypeSignature),
+}
+
+impl fmt::Display for TypeArgument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unbounded => write!(f, "?")?,
+            Self::Exact(reference) => write!(f, "{}", reference)?,
+            Self::Extends(reference) => write!(f, "? extends {}", reference)?,
+            Self::Super(reference) => write!(f, "? super {}", reference)?,
+        }
+        Ok(())
+    }
+}
+
+fn write_type_parameters(f: &mut fmt::Formatter<'_>, type_parameters: &[TypeParameter]) -> fmt::Result {
+    if !type_parameters.is_empty() {
+        write!(f, "<{}> ", join(type_parameters))?;
+    }
+    Ok(())
+}
+
+fn join<T: fmt::Display>(items: &[T]) -> String {
+    join_with(items, ", ")
+}
+
+fn join_with<T: fmt::Display>(items: &[T], separator: &str) -> String {
+    items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+// A recursive descent parser over the grammar in JVMS 4.7.9.1.
+struct Parser<'a> {
+    signature: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(signature: &'a str) -> Self {
+        Self { signature, position: 0 }
+    }
+
+    fn error(&self) -> SignatureError {
+        SignatureError::new(self.signature, self.position)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.signature[self.position..].chars().next()
+    }
+
+    fn is_end(&self) -> bool {
+        self.position == self.signature.len()
+    }
+
+    fn next(&mut self) -> Result<char, SignatureError> {
+        let c = self.peek().ok_or_else(|| self.error())?;
+        self.position += c.len_utf8();
+        Ok(c)
+    }
+
+    // Consumes `c` if it comes next.
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), SignatureError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn expect_end(&self) -> Result<(), SignatureError> {
+        if self.is_end() {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, SignatureError> {
+        let rest = &self.signature[self.position..];
+        let len = rest.find(['.', ';', '[', '/', '<', '>', ':']).unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error());
+        }
+        self.position += len;
+        Ok(rest[..len].to_string())
+    }
+
+    fn type_parameters(&mut self) -> Result<Vec<TypeParameter>, SignatureError> {
+        let mut type_parameters = vec![];
+        if !self.eat('<') {
+            return Ok(type_parameters);
+        }
+        while !self.eat('>') {
+            let name = self.identifier()?;
+            self.expect(':')?;
+            // The class bound may be empty when there are only interface bounds.
+            let class_bound = match self.peek() {
+                Some('L') | Some('T') | Some('[') => Some(self.reference_type()?),
+                _ => None,
+            };
+            let mut interface_bounds = vec![];
+            while self.eat(':') {
+                interface_bounds.push(self.reference_type()?);
+            }
+            type_parameters.push(TypeParameter {
+                name,
+                class_bound,
+                interface_bounds,
+            });
+        }
+        if type_parameters.is_empty() {
+            return Err(self.error());
+        }
+        Ok(type_parameters)
+    }
+
+    fn java_type(&mut self) -> Result<JavaTypeSignature, SignatureError> {
+        let base_type = match self.peek() {
+            Some('B') => FieldType::Byte,
+            Some('C') => FieldType::Char,
+            Some('D') => FieldType::Double,
+            Some('F') => FieldType::Float,
+            Some('I') => FieldType::Int,
+            Some('J') => FieldType::Long,
+            Some('S') => FieldType::Short,
+            Some('Z') => FieldType::Boolean,
+            _ => return Ok(JavaTypeSignature::Reference(self.reference_type()?)),
+        };
+        self.next()?;
+        Ok(JavaTypeSignature::Base(base_type))
+    }
+
+    fn reference_type(&mut self) -> Result<ReferenceTypeSignature, SignatureError> {
+        match self.peek() {
+            Some('L') => Ok(ReferenceTypeSignature::Class(self.class_type()?)),
+            Some('T') => {
+                self.next()?;
+                let name = self.identifier()?;
+                self.expect(';')?;
+                Ok(ReferenceTypeSignature::TypeVariable(name))
+            }
+            Some('[') => {
+                self.next()?;
+                Ok(ReferenceTypeSignature::Array(Box::new(self.java_type()?)))
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    fn class_type(&mut self) -> Result<ClassTypeSignature, SignatureError> {
+        self.expect('L')?;
+        let mut package = String::new();
+        let mut name = self.identifier()?;
+        while self.eat('/') {
+            package.push_str(&name);
+            package.push('/');
+            name = self.identifier()?;
+        }
+        let mut classes = vec![SimpleClassTypeSignature {
+            name,
+            type_arguments: self.type_arguments()?,
+        }];
+        while self.eat('.') {
+            classes.push(SimpleClassTypeSignature {
+                name: self.identifier()?,
+                type_arguments: self.type_arguments()?,
+            });
+        }
+        self.expect(';')?;
+        Ok(ClassTypeSignature { package, classes })
+    }
+
+    fn type_arguments(&mut self) -> Result<Vec<TypeArgument>, SignatureError> {
+        let mut type_arguments = vec![];
+        if !self.eat('<') {
+            return Ok(type_arguments);
+        }
+        while !self.eat('>') {
+            let type_argument = if self.eat('*') {
+                TypeArgument::Unbounded
+            } else if self.eat('+') {
+                TypeArgument::Extends(self.reference_type()?)
+            } else if self.eat('-') {
+                TypeArgument::Super(self.reference_type()?)
+            } else {
+                TypeArgument::Exact(self.reference_type()?)
+            };
+            type_arguments.push(type_argument);
+        }
+        if type_arguments.is_empty() {
+            return Err(self.error());
+        }
+        Ok(type_arguments)
+    }
+}
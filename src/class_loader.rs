@@ -0,0 +1,97 @@
+pub mod jar;
+
+use std::fs;
+use std::io::Cursor;
+use std::path::PathBuf;
+
+use crate::class::Class;
+use jar::Jar;
+
+enum ClasspathEntry {
+    Directory(PathBuf),
+    Jar(Jar),
+}
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-5.html#jvms-5.3
+// Finds classes in the directories and the JAR files of the class path, where a class `com/foo/Bar`
+// is stored as `com/foo/Bar.class`. The first entry that has the class file wins.
+#[derive(Default)]
+pub struct ClassLoader {
+    classpath: Vec<ClasspathEntry>,
+    // Rejects class files that have attributes unknown to rjvm.
+    strict: bool,
+}
+
+impl ClassLoader {
+    // Entries that do not exist are ignored like java does. A JAR is followed by the entries of the
+    // Class-Path attribute of its manifest.
+    pub fn new(classpath: Vec<PathBuf>, strict: bool) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut class_loader = Self {
+            classpath: vec![],
+            strict,
+        };
+        for path in classpath {
+            class_loader.add(path)?;
+        }
+        Ok(class_loader)
+    }
+
+    fn add(&mut self, path: PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+        // A JAR can be listed more than once through Class-Path, which could otherwise be circular.
+        let added = self.classpath.iter().any(|entry| match entry {
+            ClasspathEntry::Directory(dir) => *dir == path,
+            ClasspathEntry::Jar(jar) => jar.path() == path,
+        });
+        if added {
+            return Ok(());
+        }
+        if path.is_dir() {
+            self.classpath.push(ClasspathEntry::Directory(path));
+        } else if path.is_file() {
+            let mut jar = Jar::open(&path)?;
+            let class_path = jar.class_path()?;
+            self.classpath.push(ClasspathEntry::Jar(jar));
+            for path in class_path {
+                self.add(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    // Splits a class path like `classes:lib/app.jar` with the path separator of the platform.
+    pub fn parse_classpath(classpath: &str) -> Vec<PathBuf> {
+        std::env::split_paths(classpath).collect()
+    }
+
+    // Returns `None` if no entry of the class path has the class.
+    pub fn load(&mut self, binary_name: &str) -> Result<Option<Class>, Box<dyn std::error::Error>> {
+        let file_name = format!("{}.class", binary_name);
+        for entry in &mut self.classpath {
+            let data = match entry {
+                ClasspathEntry::Directory(dir) => {
+                    let path = dir.join(&file_name);
+                    if !path.is_file() {
+                        continue;
+                    }
+                    fs::read(path)?
+                }
+                ClasspathEntry::Jar(jar) => match jar.read(&file_name)? {
+                    Some(data) => data,
+                    None => continue,
+                },
+            };
+            return self.define(data).map(Some);
+        }
+        Ok(None)
+    }
+
+    fn define(&self, data: Vec<u8>) -> Result<Class, Box<dyn std::error::Error>> {
+        let rdr = Cursor::new(data);
+        let (class, _rdr) = if self.strict {
+            Class::new_strict(rdr)?
+        } else {
+            Class::new(rdr)?
+        };
+        Ok(class)
+    }
+}
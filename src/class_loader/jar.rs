@@ -0,0 +1,108 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use zip::result::ZipError;
+use zip::ZipArchive;
+
+// https://docs.oracle.com/en/java/javase/15/docs/specs/jar/jar.html
+pub struct Jar {
+    path: PathBuf,
+    archive: ZipArchive<File>,
+}
+
+impl Jar {
+    pub fn open(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let archive = ZipArchive::new(File::open(path)?)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            archive,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // Returns `None` if the JAR has no entry of the name.
+    pub fn read(&mut self, name: &str) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+        let mut file = match self.archive.by_name(name) {
+            Ok(file) => file,
+            Err(ZipError::FileNotFound) => return Ok(None),
+            Err(err) => return Err(Box::new(err)),
+        };
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)?;
+        Ok(Some(data))
+    }
+
+    pub fn manifest(&mut self) -> Result<Option<Manifest>, Box<dyn std::error::Error>> {
+        let manifest = match self.read("META-INF/MANIFEST.MF")? {
+            Some(data) => Manifest::new(&String::from_utf8_lossy(&data)),
+            None => return Ok(None),
+        };
+        Ok(Some(manifest))
+    }
+
+    // The class path entries of the Class-Path attribute, which are relative to the directory of
+    // the JAR.
+    pub fn class_path(&mut self) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+        let dir = self.path.parent().unwrap_or_else(|| Path::new("")).to_path_buf();
+        let class_path = match self.manifest()? {
+            Some(manifest) => manifest.class_path().iter().map(|entry| dir.join(entry)).collect(),
+            None => vec![],
+        };
+        Ok(class_path)
+    }
+}
+
+// https://docs.oracle.com/en/java/javase/15/docs/specs/jar/jar.html#jar-manifest
+// Only the main section is kept because the per-entry sections do not affect class loading.
+#[derive(Debug, Default)]
+pub struct Manifest {
+    attributes: Vec<(String, String)>,
+}
+
+impl Manifest {
+    pub fn new(manifest: &str) -> Self {
+        let mut attributes: Vec<(String, String)> = vec![];
+        for line in manifest.lines() {
+            let line = line.trim_end_matches('\r');
+            if line.is_empty() {
+                break;
+            }
+            // A line that starts with a space continues the value of the previous line.
+            if let Some(continuation) = line.strip_prefix(' ') {
+                if let Some((_, value)) = attributes.last_mut() {
+                    value.push_str(continuation);
+                }
+                continue;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                attributes.push((name.to_string(), value.trim_start_matches(' ').to_string()));
+            }
+        }
+        Self { attributes }
+    }
+
+    // Attribute names are case-insensitive.
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    // The binary name of the main class in internal form, e.g. `com/foo/Main`.
+    pub fn main_class(&self) -> Option<String> {
+        self.attribute("Main-Class")
+            .map(|main_class| main_class.trim().replace('.', "/"))
+    }
+
+    // The relative URLs of the Class-Path attribute, separated by spaces.
+    pub fn class_path(&self) -> Vec<&str> {
+        self.attribute("Class-Path")
+            .map(|class_path| class_path.split(' ').filter(|entry| !entry.is_empty()).collect())
+            .unwrap_or_default()
+    }
+}
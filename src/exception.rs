@@ -0,0 +1,134 @@
+use std::fmt;
+
+use crate::operand_stack::{Item, OperandStackError, StackError};
+
+// Exception classes that the VM itself throws, with their direct superclass.
+const BUILTIN_EXCEPTIONS: &[(&str, &str)] = &[
+    ("java/lang/Throwable", "java/lang/Object"),
+    ("java/lang/Exception", "java/lang/Throwable"),
+    ("java/lang/RuntimeException", "java/lang/Exception"),
+    ("java/lang/ArithmeticException", "java/lang/RuntimeException"),
+    ("java/lang/NullPointerException", "java/lang/RuntimeException"),
+    ("java/lang/IndexOutOfBoundsException", "java/lang/RuntimeException"),
+    (
+        "java/lang/ArrayIndexOutOfBoundsException",
+        "java/lang/IndexOutOfBoundsException",
+    ),
+    ("java/lang/NegativeArraySizeException", "java/lang/RuntimeException"),
+    ("java/lang/Error", "java/lang/Throwable"),
+    ("java/lang/VirtualMachineError", "java/lang/Error"),
+    ("java/lang/StackOverflowError", "java/lang/VirtualMachineError"),
+    ("java/lang/LinkageError", "java/lang/Error"),
+    ("java/lang/ExceptionInInitializerError", "java/lang/LinkageError"),
+    ("java/lang/NoClassDefFoundError", "java/lang/LinkageError"),
+    ("java/lang/ClassFormatError", "java/lang/LinkageError"),
+    ("java/lang/IncompatibleClassChangeError", "java/lang/LinkageError"),
+    (
+        "java/lang/AbstractMethodError",
+        "java/lang/IncompatibleClassChangeError",
+    ),
+    ("java/lang/NoSuchMethodError", "java/lang/IncompatibleClassChangeError"),
+];
+
+pub fn builtin_super_class(class_name: &str) -> Option<&'static str> {
+    BUILTIN_EXCEPTIONS
+        .iter()
+        .find(|(name, _)| *name == class_name)
+        .map(|(_, super_class)| *super_class)
+}
+
+#[derive(Debug, Clone)]
+pub struct Throwable {
+    pub class_name: String,
+    pub message: Option<String>,
+    pub cause: Option<Box<Throwable>>,
+}
+
+impl Throwable {
+    pub fn new(class_name: &str, message: Option<&str>, cause: Option<Throwable>) -> Self {
+        Self {
+            class_name: class_name.to_string(),
+            message: message.map(|message| message.to_string()),
+            cause: cause.map(Box::new),
+        }
+    }
+}
+
+impl fmt::Display for Throwable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.class_name.replace('/', "."))?;
+        if let Some(message) = &self.message {
+            write!(f, ": {}", message)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct UncaughtException {
+    throwable: Throwable,
+}
+
+impl UncaughtException {
+    pub fn new(throwable: Throwable) -> Self {
+        Self { throwable }
+    }
+}
+
+impl std::error::Error for UncaughtException {}
+
+impl fmt::Display for UncaughtException {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Exception in thread \"main\" {}", self.throwable)?;
+        let mut cause = &self.throwable.cause;
+        while let Some(throwable) = cause {
+            write!(f, "\nCaused by: {}", throwable)?;
+            cause = &throwable.cause;
+        }
+        Ok(())
+    }
+}
+
+// The ways an instruction can complete abruptly.
+#[derive(Debug)]
+pub enum ExecError {
+    // A Java exception was thrown. It unwinds frames until a matching handler is found.
+    Throw(Item),
+    // An operand stack operation failed. `exec_method` turns it into `StackFault` by adding where.
+    Stack(StackError),
+    StackFault(OperandStackError),
+    // The VM itself cannot continue, e.g. because of malformed bytecode.
+    Internal(String),
+}
+
+impl std::error::Error for ExecError {}
+
+impl fmt::Display for ExecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Throw(item) => write!(f, "Exception thrown: {:?}", item)?,
+            Self::Stack(err) => write!(f, "{}", err)?,
+            Self::StackFault(err) => write!(f, "{}", err)?,
+            Self::Internal(msg) => write!(f, "{}", msg)?,
+        }
+        Ok(())
+    }
+}
+
+impl From<StackError> for ExecError {
+    fn from(err: StackError) -> Self {
+        Self::Stack(err)
+    }
+}
+
+impl From<String> for ExecError {
+    fn from(msg: String) -> Self {
+        Self::Internal(msg)
+    }
+}
+
+impl From<&str> for ExecError {
+    fn from(msg: &str) -> Self {
+        Self::Internal(msg.to_string())
+    }
+}
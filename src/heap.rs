@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use crate::class::descriptor::FieldType;
+use crate::operand_stack::Item;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-2.html#jvms-2.3
+// Fields start with the zero value of their type, and references start as null.
+pub fn default_value(field_type: &FieldType) -> Item {
+    match field_type {
+        FieldType::Byte | FieldType::Char | FieldType::Int | FieldType::Short | FieldType::Boolean => Item::Int(0),
+        FieldType::Long => Item::Long(0),
+        FieldType::Float => Item::Float(0.0),
+        FieldType::Double => Item::Double(0.0),
+        FieldType::Object(_) | FieldType::Array(_) => Item::Null,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Field {
+    // The class that declares the field. A subclass can declare a field with the same name.
+    pub class_name: String,
+    pub name: String,
+    pub descriptor: String,
+    pub value: Item,
+}
+
+#[derive(Debug, Clone)]
+pub struct Object {
+    pub class_name: String,
+    // The fields of the superclasses come first, followed by the fields of the class itself.
+    pub fields: Vec<Field>,
+}
+
+impl Object {
+    pub fn new(class_name: &str, fields: Vec<Field>) -> Self {
+        Self {
+            class_name: class_name.to_string(),
+            fields,
+        }
+    }
+
+    pub fn field(&self, class_name: &str, name: &str) -> Option<&Field> {
+        self.fields
+            .iter()
+            .find(|field| field.class_name == class_name && field.name == name)
+    }
+
+    pub fn field_mut(&mut self, class_name: &str, name: &str) -> Option<&mut Field> {
+        self.fields
+            .iter_mut()
+            .find(|field| field.class_name == class_name && field.name == name)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Array {
+    // The descriptor of the array type, e.g. `[Ljava/lang/String;`.
+    pub descriptor: String,
+    pub elements: Vec<Item>,
+}
+
+impl Array {
+    pub fn new(descriptor: &str, elements: Vec<Item>) -> Self {
+        Self {
+            descriptor: descriptor.to_string(),
+            elements,
+        }
+    }
+}
+
+// Objects are never freed. An `Item::Objectref` is an index of `objects`, an `Item::Arrayref` is an
+// index of `arrays` and an `Item::String` is an index of `strings`.
+#[derive(Debug, Default)]
+pub struct Heap {
+    objects: Vec<Object>,
+    arrays: Vec<Array>,
+    strings: Vec<String>,
+    interned: HashMap<String, usize>,
+}
+
+impl Heap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn alloc(&mut self, object: Object) -> Item {
+        self.objects.push(object);
+        Item::Objectref(self.objects.len() - 1)
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Object> {
+        self.objects.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Object> {
+        self.objects.get_mut(index)
+    }
+
+    pub fn alloc_array(&mut self, array: Array) -> Item {
+        self.arrays.push(array);
+        Item::Arrayref(self.arrays.len() - 1)
+    }
+
+    pub fn array(&self, index: usize) -> Option<&Array> {
+        self.arrays.get(index)
+    }
+
+    pub fn array_mut(&mut self, index: usize) -> Option<&mut Array> {
+        self.arrays.get_mut(index)
+    }
+
+    // Equal strings share one index so that string literals compare equal with if_acmpeq like
+    // interned Java strings do (JLS 3.10.5).
+    pub fn intern(&mut self, string: &str) -> Item {
+        if let Some(index) = self.interned.get(string) {
+            return Item::String(*index);
+        }
+        self.strings.push(string.to_string());
+        self.interned.insert(string.to_string(), self.strings.len() - 1);
+        Item::String(self.strings.len() - 1)
+    }
+
+    // A string that is not interned, like one created at run time.
+    pub fn alloc_string(&mut self, string: &str) -> Item {
+        self.strings.push(string.to_string());
+        Item::String(self.strings.len() - 1)
+    }
+
+    pub fn string(&self, index: usize) -> Option<&str> {
+        self.strings.get(index).map(|string| string.as_str())
+    }
+}
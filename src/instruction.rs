@@ -1,71 +1,800 @@
+use std::error::Error;
 use std::fmt;
+use std::io;
+use std::io::Cursor;
+use std::slice::Iter;
 
+use byteorder::{BigEndian, ReadBytesExt};
+
+#[derive(Debug)]
+pub struct NotFoundOpcodeError {
+    opcode: u8,
+    wide: bool,
+}
+
+impl NotFoundOpcodeError {
+    pub fn new(opcode: u8, wide: bool) -> Self {
+        Self { opcode, wide }
+    }
+}
+
+impl Error for NotFoundOpcodeError {}
+
+impl fmt::Display for NotFoundOpcodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.wide {
+            write!(f, "Opcode 0x{:x} cannot be modified by wide", self.opcode)?;
+        } else {
+            write!(f, "Opcode 0x{:x} is not found", self.opcode)?;
+        }
+        Ok(())
+    }
+}
+
+// Decoded bytecode of a method. Each instruction keeps the offset of its opcode so that branch
+// targets, exception handlers and line numbers can be resolved against it.
+#[derive(Clone, Default)]
+pub struct InstructionStream {
+    instructions: Vec<(usize, Instruction)>,
+    code_length: usize,
+}
+
+impl InstructionStream {
+    pub fn iter(&self) -> Iter<'_, (usize, Instruction)> {
+        self.instructions.iter()
+    }
+
+    // Returns the instruction starting at `pc` and the offset of the one following it.
+    pub fn fetch(&self, pc: usize) -> Option<(&Instruction, usize)> {
+        let index = self
+            .instructions
+            .binary_search_by_key(&pc, |(offset, _)| *offset)
+            .ok()?;
+        let next_pc = self
+            .instructions
+            .get(index + 1)
+            .map_or(self.code_length, |(offset, _)| *offset);
+        Some((&self.instructions[index].1, next_pc))
+    }
+}
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-6.html#jvms-6.5
+// Local variable indexes are held as u16 so that the `wide` forms decode into the same variants.
 #[derive(PartialEq, Clone)]
 pub enum Instruction {
+    Nop,
+    AconstNull,
+    IconstM1,
+    Iconst0,
+    Iconst1,
+    Iconst2,
     Iconst3,
+    Iconst4,
     Iconst5,
+    Lconst0,
+    Lconst1,
+    Fconst0,
+    Fconst1,
+    Fconst2,
+    Dconst0,
+    Dconst1,
+    Bipush(i8),
+    Sipush(i16),
     Ldc(u8),
+    LdcW(u16),
+    Ldc2W(u16),
+    Iload(u16),
+    Lload(u16),
+    Fload(u16),
+    Dload(u16),
+    Aload(u16),
+    Iload0,
     Iload1,
     Iload2,
+    Iload3,
+    Lload0,
+    Lload1,
+    Lload2,
+    Lload3,
+    Fload0,
+    Fload1,
+    Fload2,
+    Fload3,
+    Dload0,
+    Dload1,
+    Dload2,
+    Dload3,
     Aload0,
+    Aload1,
+    Aload2,
+    Aload3,
+    Iaload,
+    Laload,
+    Faload,
+    Daload,
+    Aaload,
+    Baload,
+    Caload,
+    Saload,
+    Istore(u16),
+    Lstore(u16),
+    Fstore(u16),
+    Dstore(u16),
+    Astore(u16),
+    Istore0,
     Istore1,
     Istore2,
+    Istore3,
+    Lstore0,
+    Lstore1,
+    Lstore2,
+    Lstore3,
+    Fstore0,
+    Fstore1,
+    Fstore2,
+    Fstore3,
+    Dstore0,
+    Dstore1,
+    Dstore2,
+    Dstore3,
+    Astore0,
+    Astore1,
+    Astore2,
+    Astore3,
+    Iastore,
+    Lastore,
+    Fastore,
+    Dastore,
+    Aastore,
+    Bastore,
+    Castore,
+    Sastore,
+    Pop,
+    Pop2,
+    Dup,
+    DupX1,
+    DupX2,
+    Dup2,
+    Dup2X1,
+    Dup2X2,
+    Swap,
     Iadd,
+    Ladd,
+    Fadd,
+    Dadd,
     Isub,
+    Lsub,
+    Fsub,
+    Dsub,
+    Imul,
+    Lmul,
+    Fmul,
+    Dmul,
+    Idiv,
+    Ldiv,
+    Fdiv,
+    Ddiv,
+    Irem,
+    Lrem,
+    Frem,
+    Drem,
+    Ineg,
+    Lneg,
+    Fneg,
+    Dneg,
+    Ishl,
+    Lshl,
+    Ishr,
+    Lshr,
+    Iushr,
+    Lushr,
+    Iand,
+    Land,
+    Ior,
+    Lor,
+    Ixor,
+    Lxor,
+    Iinc(u16, i16),
+    I2l,
+    I2f,
+    I2d,
+    L2i,
+    L2f,
+    L2d,
+    F2i,
+    F2l,
+    F2d,
+    D2i,
+    D2l,
+    D2f,
+    I2b,
+    I2c,
+    I2s,
+    Lcmp,
+    Fcmpl,
+    Fcmpg,
+    Dcmpl,
+    Dcmpg,
+    Ifeq(i16),
+    Ifne(i16),
+    Iflt(i16),
+    Ifge(i16),
+    Ifgt(i16),
+    Ifle(i16),
+    IfIcmpeq(i16),
+    IfIcmpne(i16),
+    IfIcmplt(i16),
+    IfIcmpge(i16),
+    IfIcmpgt(i16),
+    IfIcmple(i16),
+    IfAcmpeq(i16),
+    IfAcmpne(i16),
+    Goto(i16),
+    Jsr(i16),
+    Ret(u16),
+    Tableswitch {
+        default: i32,
+        low: i32,
+        high: i32,
+        offsets: Vec<i32>,
+    },
+    Lookupswitch {
+        default: i32,
+        pairs: Vec<(i32, i32)>,
+    },
+    Ireturn,
+    Lreturn,
+    Freturn,
+    Dreturn,
+    Areturn,
     Return,
-    GetStatic(u8, u8),
-    InvokeVirtual(u8, u8),
-    Invokespecial(u8, u8),
+    GetStatic(u16),
+    PutStatic(u16),
+    GetField(u16),
+    PutField(u16),
+    InvokeVirtual(u16),
+    Invokespecial(u16),
+    InvokeStatic(u16),
+    InvokeInterface(u16, u8),
+    InvokeDynamic(u16),
+    New(u16),
+    Newarray(u8),
+    Anewarray(u16),
+    Arraylength,
+    Athrow,
+    Checkcast(u16),
+    Instanceof(u16),
+    Monitorenter,
+    Monitorexit,
+    Multianewarray(u16, u8),
+    Ifnull(i16),
+    Ifnonnull(i16),
+    GotoW(i32),
+    JsrW(i32),
 }
 
 impl Instruction {
-    pub fn from_codes(mut codes: Vec<u8>) -> Vec<Instruction> {
-        let mut instructions: Vec<Instruction> = vec![];
-        loop {
-            if codes.is_empty() {
-                break;
-            }
-            let inst = match codes.pop() {
-                Some(0x06) => Instruction::Iconst3,
-                Some(0x08) => Instruction::Iconst5,
-                Some(0x12) => Instruction::Ldc(codes.pop().unwrap()),
-                Some(0x1b) => Instruction::Iload1,
-                Some(0x1c) => Instruction::Iload2,
-                Some(0x2a) => Instruction::Aload0,
-                Some(0x3c) => Instruction::Istore1,
-                Some(0x3d) => Instruction::Istore2,
-                Some(0x60) => Instruction::Iadd,
-                Some(0x64) => Instruction::Isub,
-                Some(0xb1) => Instruction::Return,
-                Some(0xb2) => Instruction::GetStatic(codes.pop().unwrap(), codes.pop().unwrap()),
-                Some(0xb6) => Instruction::InvokeVirtual(codes.pop().unwrap(), codes.pop().unwrap()),
-                Some(0xb7) => Instruction::Invokespecial(codes.pop().unwrap(), codes.pop().unwrap()),
-                Some(code) => unimplemented!("code {:x}", code),
-                None => panic!(),
+    pub fn from_codes(codes: &[u8]) -> Result<InstructionStream, Box<dyn Error>> {
+        let mut rdr = Cursor::new(codes);
+        let mut instructions = Vec::new();
+        while (rdr.position() as usize) < codes.len() {
+            let offset = rdr.position() as usize;
+            let inst = match rdr.read_u8()? {
+                0xc4 => {
+                    let opcode = rdr.read_u8()?;
+                    Self::decode(opcode, &mut rdr, true)?
+                }
+                opcode => Self::decode(opcode, &mut rdr, false)?,
             };
-            instructions.push(inst);
+            instructions.push((offset, inst));
         }
-        instructions
+        Ok(InstructionStream {
+            instructions,
+            code_length: codes.len(),
+        })
+    }
+
+    fn decode(opcode: u8, rdr: &mut Cursor<&[u8]>, wide: bool) -> Result<Instruction, Box<dyn Error>> {
+        if wide && !matches!(opcode, 0x15..=0x19 | 0x36..=0x3a | 0x84 | 0xa9) {
+            return Err(Box::new(NotFoundOpcodeError::new(opcode, wide)));
+        }
+        let inst = match opcode {
+            0x00 => Self::Nop,
+            0x01 => Self::AconstNull,
+            0x02 => Self::IconstM1,
+            0x03 => Self::Iconst0,
+            0x04 => Self::Iconst1,
+            0x05 => Self::Iconst2,
+            0x06 => Self::Iconst3,
+            0x07 => Self::Iconst4,
+            0x08 => Self::Iconst5,
+            0x09 => Self::Lconst0,
+            0x0a => Self::Lconst1,
+            0x0b => Self::Fconst0,
+            0x0c => Self::Fconst1,
+            0x0d => Self::Fconst2,
+            0x0e => Self::Dconst0,
+            0x0f => Self::Dconst1,
+            0x10 => Self::Bipush(rdr.read_i8()?),
+            0x11 => Self::Sipush(rdr.read_i16::<BigEndian>()?),
+            0x12 => Self::Ldc(rdr.read_u8()?),
+            0x13 => Self::LdcW(rdr.read_u16::<BigEndian>()?),
+            0x14 => Self::Ldc2W(rdr.read_u16::<BigEndian>()?),
+            0x15 => Self::Iload(read_local(rdr, wide)?),
+            0x16 => Self::Lload(read_local(rdr, wide)?),
+            0x17 => Self::Fload(read_local(rdr, wide)?),
+            0x18 => Self::Dload(read_local(rdr, wide)?),
+            0x19 => Self::Aload(read_local(rdr, wide)?),
+            0x1a => Self::Iload0,
+            0x1b => Self::Iload1,
+            0x1c => Self::Iload2,
+            0x1d => Self::Iload3,
+            0x1e => Self::Lload0,
+            0x1f => Self::Lload1,
+            0x20 => Self::Lload2,
+            0x21 => Self::Lload3,
+            0x22 => Self::Fload0,
+            0x23 => Self::Fload1,
+            0x24 => Self::Fload2,
+            0x25 => Self::Fload3,
+            0x26 => Self::Dload0,
+            0x27 => Self::Dload1,
+            0x28 => Self::Dload2,
+            0x29 => Self::Dload3,
+            0x2a => Self::Aload0,
+            0x2b => Self::Aload1,
+            0x2c => Self::Aload2,
+            0x2d => Self::Aload3,
+            0x2e => Self::Iaload,
+            0x2f => Self::Laload,
+            0x30 => Self::Faload,
+            0x31 => Self::Daload,
+            0x32 => Self::Aaload,
+            0x33 => Self::Baload,
+            0x34 => Self::Caload,
+            0x35 => Self::Saload,
+            0x36 => Self::Istore(read_local(rdr, wide)?),
+            0x37 => Self::Lstore(read_local(rdr, wide)?),
+            0x38 => Self::Fstore(read_local(rdr, wide)?),
+            0x39 => Self::Dstore(read_local(rdr, wide)?),
+            0x3a => Self::Astore(read_local(rdr, wide)?),
+            0x3b => Self::Istore0,
+            0x3c => Self::Istore1,
+            0x3d => Self::Istore2,
+            0x3e => Self::Istore3,
+            0x3f => Self::Lstore0,
+            0x40 => Self::Lstore1,
+            0x41 => Self::Lstore2,
+            0x42 => Self::Lstore3,
+            0x43 => Self::Fstore0,
+            0x44 => Self::Fstore1,
+            0x45 => Self::Fstore2,
+            0x46 => Self::Fstore3,
+            0x47 => Self::Dstore0,
+            0x48 => Self::Dstore1,
+            0x49 => Self::Dstore2,
+            0x4a => Self::Dstore3,
+            0x4b => Self::Astore0,
+            0x4c => Self::Astore1,
+            0x4d => Self::Astore2,
+            0x4e => Self::Astore3,
+            0x4f => Self::Iastore,
+            0x50 => Self::Lastore,
+            0x51 => Self::Fastore,
+            0x52 => Self::Dastore,
+            0x53 => Self::Aastore,
+            0x54 => Self::Bastore,
+            0x55 => Self::Castore,
+            0x56 => Self::Sastore,
+            0x57 => Self::Pop,
+            0x58 => Self::Pop2,
+            0x59 => Self::Dup,
+            0x5a => Self::DupX1,
+            0x5b => Self::DupX2,
+            0x5c => Self::Dup2,
+            0x5d => Self::Dup2X1,
+            0x5e => Self::Dup2X2,
+            0x5f => Self::Swap,
+            0x60 => Self::Iadd,
+            0x61 => Self::Ladd,
+            0x62 => Self::Fadd,
+            0x63 => Self::Dadd,
+            0x64 => Self::Isub,
+            0x65 => Self::Lsub,
+            0x66 => Self::Fsub,
+            0x67 => Self::Dsub,
+            0x68 => Self::Imul,
+            0x69 => Self::Lmul,
+            0x6a => Self::Fmul,
+            0x6b => Self::Dmul,
+            0x6c => Self::Idiv,
+            0x6d => Self::Ldiv,
+            0x6e => Self::Fdiv,
+            0x6f => Self::Ddiv,
+            0x70 => Self::Irem,
+            0x71 => Self::Lrem,
+            0x72 => Self::Frem,
+            0x73 => Self::Drem,
+            0x74 => Self::Ineg,
+            0x75 => Self::Lneg,
+            0x76 => Self::Fneg,
+            0x77 => Self::Dneg,
+            0x78 => Self::Ishl,
+            0x79 => Self::Lshl,
+            0x7a => Self::Ishr,
+            0x7b => Self::Lshr,
+            0x7c => Self::Iushr,
+            0x7d => Self::Lushr,
+            0x7e => Self::Iand,
+            0x7f => Self::Land,
+            0x80 => Self::Ior,
+            0x81 => Self::Lor,
+            0x82 => Self::Ixor,
+            0x83 => Self::Lxor,
+            0x84 => {
+                let index = read_local(rdr, wide)?;
+                let constant = if wide {
+                    rdr.read_i16::<BigEndian>()?
+                } else {
+                    i16::from(rdr.read_i8()?)
+                };
+                Self::Iinc(index, constant)
+            }
+            0x85 => Self::I2l,
+            0x86 => Self::I2f,
+            0x87 => Self::I2d,
+            0x88 => Self::L2i,
+            0x89 => Self::L2f,
+            0x8a => Self::L2d,
+            0x8b => Self::F2i,
+            0x8c => Self::F2l,
+            0x8d => Self::F2d,
+            0x8e => Self::D2i,
+            0x8f => Self::D2l,
+            0x90 => Self::D2f,
+            0x91 => Self::I2b,
+            0x92 => Self::I2c,
+            0x93 => Self::I2s,
+            0x94 => Self::Lcmp,
+            0x95 => Self::Fcmpl,
+            0x96 => Self::Fcmpg,
+            0x97 => Self::Dcmpl,
+            0x98 => Self::Dcmpg,
+            0x99 => Self::Ifeq(rdr.read_i16::<BigEndian>()?),
+            0x9a => Self::Ifne(rdr.read_i16::<BigEndian>()?),
+            0x9b => Self::Iflt(rdr.read_i16::<BigEndian>()?),
+            0x9c => Self::Ifge(rdr.read_i16::<BigEndian>()?),
+            0x9d => Self::Ifgt(rdr.read_i16::<BigEndian>()?),
+            0x9e => Self::Ifle(rdr.read_i16::<BigEndian>()?),
+            0x9f => Self::IfIcmpeq(rdr.read_i16::<BigEndian>()?),
+            0xa0 => Self::IfIcmpne(rdr.read_i16::<BigEndian>()?),
+            0xa1 => Self::IfIcmplt(rdr.read_i16::<BigEndian>()?),
+            0xa2 => Self::IfIcmpge(rdr.read_i16::<BigEndian>()?),
+            0xa3 => Self::IfIcmpgt(rdr.read_i16::<BigEndian>()?),
+            0xa4 => Self::IfIcmple(rdr.read_i16::<BigEndian>()?),
+            0xa5 => Self::IfAcmpeq(rdr.read_i16::<BigEndian>()?),
+            0xa6 => Self::IfAcmpne(rdr.read_i16::<BigEndian>()?),
+            0xa7 => Self::Goto(rdr.read_i16::<BigEndian>()?),
+            0xa8 => Self::Jsr(rdr.read_i16::<BigEndian>()?),
+            0xa9 => Self::Ret(read_local(rdr, wide)?),
+            0xaa => {
+                skip_padding(rdr)?;
+                let default = rdr.read_i32::<BigEndian>()?;
+                let low = rdr.read_i32::<BigEndian>()?;
+                let high = rdr.read_i32::<BigEndian>()?;
+                if low > high {
+                    return Err(Box::new(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("tableswitch low {} is greater than high {}", low, high),
+                    )));
+                }
+                let offsets = (low..=high)
+                    .map(|_| rdr.read_i32::<BigEndian>())
+                    .collect::<Result<Vec<_>, _>>()?;
+                Self::Tableswitch {
+                    default,
+                    low,
+                    high,
+                    offsets,
+                }
+            }
+            0xab => {
+                skip_padding(rdr)?;
+                let default = rdr.read_i32::<BigEndian>()?;
+                let npairs = rdr.read_i32::<BigEndian>()?;
+                if npairs < 0 {
+                    return Err(Box::new(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("lookupswitch npairs {} is negative", npairs),
+                    )));
+                }
+                let pairs = (0..npairs)
+                    .map(|_| Ok((rdr.read_i32::<BigEndian>()?, rdr.read_i32::<BigEndian>()?)))
+                    .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+                Self::Lookupswitch { default, pairs }
+            }
+            0xac => Self::Ireturn,
+            0xad => Self::Lreturn,
+            0xae => Self::Freturn,
+            0xaf => Self::Dreturn,
+            0xb0 => Self::Areturn,
+            0xb1 => Self::Return,
+            0xb2 => Self::GetStatic(rdr.read_u16::<BigEndian>()?),
+            0xb3 => Self::PutStatic(rdr.read_u16::<BigEndian>()?),
+            0xb4 => Self::GetField(rdr.read_u16::<BigEndian>()?),
+            0xb5 => Self::PutField(rdr.read_u16::<BigEndian>()?),
+            0xb6 => Self::InvokeVirtual(rdr.read_u16::<BigEndian>()?),
+            0xb7 => Self::Invokespecial(rdr.read_u16::<BigEndian>()?),
+            0xb8 => Self::InvokeStatic(rdr.read_u16::<BigEndian>()?),
+            0xb9 => {
+                let index = rdr.read_u16::<BigEndian>()?;
+                let count = rdr.read_u8()?;
+                let _zero = rdr.read_u8()?;
+                Self::InvokeInterface(index, count)
+            }
+            0xba => {
+                let index = rdr.read_u16::<BigEndian>()?;
+                let _zero = rdr.read_u16::<BigEndian>()?;
+                Self::InvokeDynamic(index)
+            }
+            0xbb => Self::New(rdr.read_u16::<BigEndian>()?),
+            0xbc => Self::Newarray(rdr.read_u8()?),
+            0xbd => Self::Anewarray(rdr.read_u16::<BigEndian>()?),
+            0xbe => Self::Arraylength,
+            0xbf => Self::Athrow,
+            0xc0 => Self::Checkcast(rdr.read_u16::<BigEndian>()?),
+            0xc1 => Self::Instanceof(rdr.read_u16::<BigEndian>()?),
+            0xc2 => Self::Monitorenter,
+            0xc3 => Self::Monitorexit,
+            0xc5 => Self::Multianewarray(rdr.read_u16::<BigEndian>()?, rdr.read_u8()?),
+            0xc6 => Self::Ifnull(rdr.read_i16::<BigEndian>()?),
+            0xc7 => Self::Ifnonnull(rdr.read_i16::<BigEndian>()?),
+            0xc8 => Self::GotoW(rdr.read_i32::<BigEndian>()?),
+            0xc9 => Self::JsrW(rdr.read_i32::<BigEndian>()?),
+            opcode => return Err(Box::new(NotFoundOpcodeError::new(opcode, wide))),
+        };
+        Ok(inst)
     }
 }
 
+fn read_local(rdr: &mut Cursor<&[u8]>, wide: bool) -> io::Result<u16> {
+    if wide {
+        rdr.read_u16::<BigEndian>()
+    } else {
+        rdr.read_u8().map(u16::from)
+    }
+}
+
+// The operands of tableswitch and lookupswitch start at an address that is a multiple of four
+// bytes from the start of the method.
+fn skip_padding(rdr: &mut Cursor<&[u8]>) -> io::Result<()> {
+    while !rdr.position().is_multiple_of(4) {
+        rdr.read_u8()?;
+    }
+    Ok(())
+}
+
 impl fmt::Display for Instruction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &*self {
+        match self {
+            Self::Nop => write!(f, "Nop")?,
+            Self::AconstNull => write!(f, "AconstNull")?,
+            Self::IconstM1 => write!(f, "IconstM1")?,
+            Self::Iconst0 => write!(f, "Iconst0")?,
+            Self::Iconst1 => write!(f, "Iconst1")?,
+            Self::Iconst2 => write!(f, "Iconst2")?,
             Self::Iconst3 => write!(f, "Iconst3")?,
+            Self::Iconst4 => write!(f, "Iconst4")?,
             Self::Iconst5 => write!(f, "Iconst5")?,
+            Self::Lconst0 => write!(f, "Lconst0")?,
+            Self::Lconst1 => write!(f, "Lconst1")?,
+            Self::Fconst0 => write!(f, "Fconst0")?,
+            Self::Fconst1 => write!(f, "Fconst1")?,
+            Self::Fconst2 => write!(f, "Fconst2")?,
+            Self::Dconst0 => write!(f, "Dconst0")?,
+            Self::Dconst1 => write!(f, "Dconst1")?,
+            Self::Bipush(arg) => write!(f, "Bipush({})", arg)?,
+            Self::Sipush(arg) => write!(f, "Sipush({})", arg)?,
             Self::Ldc(arg) => write!(f, "Ldc({})", arg)?,
+            Self::LdcW(arg) => write!(f, "LdcW({})", arg)?,
+            Self::Ldc2W(arg) => write!(f, "Ldc2W({})", arg)?,
+            Self::Iload(arg) => write!(f, "Iload({})", arg)?,
+            Self::Lload(arg) => write!(f, "Lload({})", arg)?,
+            Self::Fload(arg) => write!(f, "Fload({})", arg)?,
+            Self::Dload(arg) => write!(f, "Dload({})", arg)?,
+            Self::Aload(arg) => write!(f, "Aload({})", arg)?,
+            Self::Iload0 => write!(f, "Iload0")?,
             Self::Iload1 => write!(f, "Iload1")?,
             Self::Iload2 => write!(f, "Iload2")?,
+            Self::Iload3 => write!(f, "Iload3")?,
+            Self::Lload0 => write!(f, "Lload0")?,
+            Self::Lload1 => write!(f, "Lload1")?,
+            Self::Lload2 => write!(f, "Lload2")?,
+            Self::Lload3 => write!(f, "Lload3")?,
+            Self::Fload0 => write!(f, "Fload0")?,
+            Self::Fload1 => write!(f, "Fload1")?,
+            Self::Fload2 => write!(f, "Fload2")?,
+            Self::Fload3 => write!(f, "Fload3")?,
+            Self::Dload0 => write!(f, "Dload0")?,
+            Self::Dload1 => write!(f, "Dload1")?,
+            Self::Dload2 => write!(f, "Dload2")?,
+            Self::Dload3 => write!(f, "Dload3")?,
             Self::Aload0 => write!(f, "Aload0")?,
+            Self::Aload1 => write!(f, "Aload1")?,
+            Self::Aload2 => write!(f, "Aload2")?,
+            Self::Aload3 => write!(f, "Aload3")?,
+            Self::Iaload => write!(f, "Iaload")?,
+            Self::Laload => write!(f, "Laload")?,
+            Self::Faload => write!(f, "Faload")?,
+            Self::Daload => write!(f, "Daload")?,
+            Self::Aaload => write!(f, "Aaload")?,
+            Self::Baload => write!(f, "Baload")?,
+            Self::Caload => write!(f, "Caload")?,
+            Self::Saload => write!(f, "Saload")?,
+            Self::Istore(arg) => write!(f, "Istore({})", arg)?,
+            Self::Lstore(arg) => write!(f, "Lstore({})", arg)?,
+            Self::Fstore(arg) => write!(f, "Fstore({})", arg)?,
+            Self::Dstore(arg) => write!(f, "Dstore({})", arg)?,
+            Self::Astore(arg) => write!(f, "Astore({})", arg)?,
+            Self::Istore0 => write!(f, "Istore0")?,
             Self::Istore1 => write!(f, "Istore1")?,
             Self::Istore2 => write!(f, "Istore2")?,
+            Self::Istore3 => write!(f, "Istore3")?,
+            Self::Lstore0 => write!(f, "Lstore0")?,
+            Self::Lstore1 => write!(f, "Lstore1")?,
+            Self::Lstore2 => write!(f, "Lstore2")?,
+            Self::Lstore3 => write!(f, "Lstore3")?,
+            Self::Fstore0 => write!(f, "Fstore0")?,
+            Self::Fstore1 => write!(f, "Fstore1")?,
+            Self::Fstore2 => write!(f, "Fstore2")?,
+            Self::Fstore3 => write!(f, "Fstore3")?,
+            Self::Dstore0 => write!(f, "Dstore0")?,
+            Self::Dstore1 => write!(f, "Dstore1")?,
+            Self::Dstore2 => write!(f, "Dstore2")?,
+            Self::Dstore3 => write!(f, "Dstore3")?,
+            Self::Astore0 => write!(f, "Astore0")?,
+            Self::Astore1 => write!(f, "Astore1")?,
+            Self::Astore2 => write!(f, "Astore2")?,
+            Self::Astore3 => write!(f, "Astore3")?,
+            Self::Iastore => write!(f, "Iastore")?,
+            Self::Lastore => write!(f, "Lastore")?,
+            Self::Fastore => write!(f, "Fastore")?,
+            Self::Dastore => write!(f, "Dastore")?,
+            Self::Aastore => write!(f, "Aastore")?,
+            Self::Bastore => write!(f, "Bastore")?,
+            Self::Castore => write!(f, "Castore")?,
+            Self::Sastore => write!(f, "Sastore")?,
+            Self::Pop => write!(f, "Pop")?,
+            Self::Pop2 => write!(f, "Pop2")?,
+            Self::Dup => write!(f, "Dup")?,
+            Self::DupX1 => write!(f, "DupX1")?,
+            Self::DupX2 => write!(f, "DupX2")?,
+            Self::Dup2 => write!(f, "Dup2")?,
+            Self::Dup2X1 => write!(f, "Dup2X1")?,
+            Self::Dup2X2 => write!(f, "Dup2X2")?,
+            Self::Swap => write!(f, "Swap")?,
             Self::Iadd => write!(f, "Iadd")?,
+            Self::Ladd => write!(f, "Ladd")?,
+            Self::Fadd => write!(f, "Fadd")?,
+            Self::Dadd => write!(f, "Dadd")?,
             Self::Isub => write!(f, "Isub")?,
+            Self::Lsub => write!(f, "Lsub")?,
+            Self::Fsub => write!(f, "Fsub")?,
+            Self::Dsub => write!(f, "Dsub")?,
+            Self::Imul => write!(f, "Imul")?,
+            Self::Lmul => write!(f, "Lmul")?,
+            Self::Fmul => write!(f, "Fmul")?,
+            Self::Dmul => write!(f, "Dmul")?,
+            Self::Idiv => write!(f, "Idiv")?,
+            Self::Ldiv => write!(f, "Ldiv")?,
+            Self::Fdiv => write!(f, "Fdiv")?,
+            Self::Ddiv => write!(f, "Ddiv")?,
+            Self::Irem => write!(f, "Irem")?,
+            Self::Lrem => write!(f, "Lrem")?,
+            Self::Frem => write!(f, "Frem")?,
+            Self::Drem => write!(f, "Drem")?,
+            Self::Ineg => write!(f, "Ineg")?,
+            Self::Lneg => write!(f, "Lneg")?,
+            Self::Fneg => write!(f, "Fneg")?,
+            Self::Dneg => write!(f, "Dneg")?,
+            Self::Ishl => write!(f, "Ishl")?,
+            Self::Lshl => write!(f, "Lshl")?,
+            Self::Ishr => write!(f, "Ishr")?,
+            Self::Lshr => write!(f, "Lshr")?,
+            Self::Iushr => write!(f, "Iushr")?,
+            Self::Lushr => write!(f, "Lushr")?,
+            Self::Iand => write!(f, "Iand")?,
+            Self::Land => write!(f, "Land")?,
+            Self::Ior => write!(f, "Ior")?,
+            Self::Lor => write!(f, "Lor")?,
+            Self::Ixor => write!(f, "Ixor")?,
+            Self::Lxor => write!(f, "Lxor")?,
+            Self::Iinc(arg1, arg2) => write!(f, "Iinc({}, {})", arg1, arg2)?,
+            Self::I2l => write!(f, "I2l")?,
+            Self::I2f => write!(f, "I2f")?,
+            Self::I2d => write!(f, "I2d")?,
+            Self::L2i => write!(f, "L2i")?,
+            Self::L2f => write!(f, "L2f")?,
+            Self::L2d => write!(f, "L2d")?,
+            Self::F2i => write!(f, "F2i")?,
+            Self::F2l => write!(f, "F2l")?,
+            Self::F2d => write!(f, "F2d")?,
+            Self::D2i => write!(f, "D2i")?,
+            Self::D2l => write!(f, "D2l")?,
+            Self::D2f => write!(f, "D2f")?,
+            Self::I2b => write!(f, "I2b")?,
+            Self::I2c => write!(f, "I2c")?,
+            Self::I2s => write!(f, "I2s")?,
+            Self::Lcmp => write!(f, "Lcmp")?,
+            Self::Fcmpl => write!(f, "Fcmpl")?,
+            Self::Fcmpg => write!(f, "Fcmpg")?,
+            Self::Dcmpl => write!(f, "Dcmpl")?,
+            Self::Dcmpg => write!(f, "Dcmpg")?,
+            Self::Ifeq(arg) => write!(f, "Ifeq({})", arg)?,
+            Self::Ifne(arg) => write!(f, "Ifne({})", arg)?,
+            Self::Iflt(arg) => write!(f, "Iflt({})", arg)?,
+            Self::Ifge(arg) => write!(f, "Ifge({})", arg)?,
+            Self::Ifgt(arg) => write!(f, "Ifgt({})", arg)?,
+            Self::Ifle(arg) => write!(f, "Ifle({})", arg)?,
+            Self::IfIcmpeq(arg) => write!(f, "IfIcmpeq({})", arg)?,
+            Self::IfIcmpne(arg) => write!(f, "IfIcmpne({})", arg)?,
+            Self::IfIcmplt(arg) => write!(f, "IfIcmplt({})", arg)?,
+            Self::IfIcmpge(arg) => write!(f, "IfIcmpge({})", arg)?,
+            Self::IfIcmpgt(arg) => write!(f, "IfIcmpgt({})", arg)?,
+            Self::IfIcmple(arg) => write!(f, "IfIcmple({})", arg)?,
+            Self::IfAcmpeq(arg) => write!(f, "IfAcmpeq({})", arg)?,
+            Self::IfAcmpne(arg) => write!(f, "IfAcmpne({})", arg)?,
+            Self::Goto(arg) => write!(f, "Goto({})", arg)?,
+            Self::Jsr(arg) => write!(f, "Jsr({})", arg)?,
+            Self::Ret(arg) => write!(f, "Ret({})", arg)?,
+            Self::Tableswitch {
+                default,
+                low,
+                high,
+                offsets,
+            } => {
+                write!(f, "Tableswitch({}..={}", low, high)?;
+                for (key, offset) in (*low..=*high).zip(offsets) {
+                    write!(f, ", {}: {}", key, offset)?;
+                }
+                write!(f, ", default: {})", default)?;
+            }
+            Self::Lookupswitch { default, pairs } => {
+                write!(f, "Lookupswitch(")?;
+                for (key, offset) in pairs {
+                    write!(f, "{}: {}, ", key, offset)?;
+                }
+                write!(f, "default: {})", default)?;
+            }
+            Self::Ireturn => write!(f, "Ireturn")?,
+            Self::Lreturn => write!(f, "Lreturn")?,
+            Self::Freturn => write!(f, "Freturn")?,
+            Self::Dreturn => write!(f, "Dreturn")?,
+            Self::Areturn => write!(f, "Areturn")?,
             Self::Return => write!(f, "Return")?,
-            Self::GetStatic(arg1, arg2) => write!(f, "GetStatic({}, {})", arg1, arg2)?,
-            Self::InvokeVirtual(arg1, arg2) => write!(f, "InvokeVirtual({}, {})", arg1, arg2)?,
-            Self::Invokespecial(arg1, arg2) => write!(f, "Invokespecial({}, {})", arg1, arg2)?,
+            Self::GetStatic(arg) => write!(f, "GetStatic({})", arg)?,
+            Self::PutStatic(arg) => write!(f, "PutStatic({})", arg)?,
+            Self::GetField(arg) => write!(f, "GetField({})", arg)?,
+            Self::PutField(arg) => write!(f, "PutField({})", arg)?,
+            Self::InvokeVirtual(arg) => write!(f, "InvokeVirtual({})", arg)?,
+            Self::Invokespecial(arg) => write!(f, "Invokespecial({})", arg)?,
+            Self::InvokeStatic(arg) => write!(f, "InvokeStatic({})", arg)?,
+            Self::InvokeInterface(arg1, arg2) => write!(f, "InvokeInterface({}, {})", arg1, arg2)?,
+            Self::InvokeDynamic(arg) => write!(f, "InvokeDynamic({})", arg)?,
+            Self::New(arg) => write!(f, "New({})", arg)?,
+            Self::Newarray(arg) => write!(f, "Newarray({})", arg)?,
+            Self::Anewarray(arg) => write!(f, "Anewarray({})", arg)?,
+            Self::Arraylength => write!(f, "Arraylength")?,
+            Self::Athrow => write!(f, "Athrow")?,
+            Self::Checkcast(arg) => write!(f, "Checkcast({})", arg)?,
+            Self::Instanceof(arg) => write!(f, "Instanceof({})", arg)?,
+            Self::Monitorenter => write!(f, "Monitorenter")?,
+            Self::Monitorexit => write!(f, "Monitorexit")?,
+            Self::Multianewarray(arg1, arg2) => write!(f, "Multianewarray({}, {})", arg1, arg2)?,
+            Self::Ifnull(arg) => write!(f, "Ifnull({})", arg)?,
+            Self::Ifnonnull(arg) => write!(f, "Ifnonnull({})", arg)?,
+            Self::GotoW(arg) => write!(f, "GotoW({})", arg)?,
+            Self::JsrW(arg) => write!(f, "JsrW({})", arg)?,
         };
         Ok(())
     }
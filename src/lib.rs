@@ -1,4 +1,9 @@
 pub mod class;
+pub mod class_loader;
+pub mod exception;
+pub mod heap;
 pub mod instruction;
+pub mod local_variable;
+pub mod method_area;
 pub mod operand_stack;
 pub mod vm;
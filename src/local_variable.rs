@@ -0,0 +1,51 @@
+use crate::operand_stack::Item;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-2.html#jvms-2.6.1
+// A long or double occupies the slot at its index and the next one; the next slot is held as
+// `None` so that it cannot be read on its own.
+#[derive(Debug, Clone, Default)]
+pub struct LocalVariable {
+    slots: Vec<Option<Item>>,
+}
+
+impl LocalVariable {
+    pub fn new(max_locals: usize) -> Self {
+        Self {
+            slots: vec![None; max_locals],
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Result<Item, String> {
+        match self.slots.get(index) {
+            Some(Some(item)) => Ok(*item),
+            Some(None) => Err(format!("Local variable {} is not initialized", index)),
+            None => Err(format!(
+                "Local variable {} is out of max_locals {}",
+                index,
+                self.slots.len()
+            )),
+        }
+    }
+
+    pub fn set(&mut self, index: usize, item: Item) -> Result<(), String> {
+        let last = if item.is_category2() { index + 1 } else { index };
+        if last >= self.slots.len() {
+            return Err(format!(
+                "Local variable {} is out of max_locals {}",
+                last,
+                self.slots.len()
+            ));
+        }
+        // Overwriting the second half of a long or double makes the whole value unusable.
+        if let Some(prev) = index.checked_sub(1) {
+            if self.slots[prev].is_some_and(|item| item.is_category2()) {
+                self.slots[prev] = None;
+            }
+        }
+        self.slots[index] = Some(item);
+        if item.is_category2() {
+            self.slots[index + 1] = None;
+        }
+        Ok(())
+    }
+}
@@ -1,8 +1,7 @@
-use std::fs::File;
-use std::io::Cursor;
-use std::io::Read;
+use std::path::Path;
 
-use rjvm::class::Class;
+use rjvm::class_loader::jar::Jar;
+use rjvm::class_loader::ClassLoader;
 use rjvm::vm::VM;
 
 use clap::Clap;
@@ -10,26 +9,93 @@ use clap::Clap;
 #[derive(Clap, Debug)]
 #[clap(version = "1.0", author = "uttam0k <k0ma@utam0k.jp>")]
 struct Opts {
-    class_file: String,
+    /// The binary name of the class whose main method is run, e.g. com.example.Main
+    #[clap(required_unless_present = "jar")]
+    main_class: Option<String>,
+    /// The arguments passed to the main method
+    args: Vec<String>,
+    /// Directories and JAR files to search for classes, separated like PATH (-cp). Defaults to the current directory
+    #[clap(long)]
+    classpath: Option<String>,
+    /// Run the Main-Class of the manifest of the JAR file, with the JAR as the class path (-jar)
+    #[clap(long)]
+    jar: Option<String>,
     #[clap(short)]
     verbose: bool,
+    /// Reject class files that have attributes unknown to rjvm
+    #[clap(long)]
+    strict: bool,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let opts = Opts::parse();
-    let mut file = File::open(opts.class_file)?;
+// clap has no two-letter short options, so `-cp` and `-jar` are taken as the long options like java
+// does. Everything after the main class or the JAR belongs to the main method.
+fn java_style_args() -> Vec<String> {
+    let mut args = vec![];
+    let mut rest = std::env::args();
+    args.extend(rest.next());
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "-cp" | "--classpath" => {
+                args.push("--classpath".to_string());
+                args.extend(rest.next());
+            }
+            "-jar" | "--jar" => {
+                args.push("--jar".to_string());
+                args.extend(rest.next());
+                break;
+            }
+            _ if arg.starts_with('-') => args.push(arg),
+            _ => {
+                args.push(arg);
+                break;
+            }
+        }
+    }
+    // The main method arguments follow `--` so that clap does not take them as options.
+    args.push("--".to_string());
+    args.extend(rest);
+    args
+}
 
-    let mut data = Vec::new();
-    file.read_to_end(&mut data)?;
-    let rdr = Cursor::new(data);
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let opts = Opts::parse_from(java_style_args());
 
-    let (class, _rdr) = Class::new(rdr)?;
+    let (main_class, args, mut class_loader) = match &opts.jar {
+        Some(jar) => {
+            let path = Path::new(jar);
+            let main_class = Jar::open(path)
+                .and_then(|mut jar| jar.manifest())?
+                .and_then(|manifest| manifest.main_class())
+                .ok_or_else(|| format!("no main manifest attribute, in {}", jar))?;
+            // The class path is the JAR and its Class-Path, and -cp is ignored. The main class cannot
+            // be given with -jar, so all the positional arguments are for the main method.
+            let class_loader = ClassLoader::new(vec![path.to_path_buf()], opts.strict)?;
+            let args = opts.main_class.iter().chain(&opts.args).cloned().collect();
+            (main_class, args, class_loader)
+        }
+        None => {
+            let main_class = opts
+                .main_class
+                .as_ref()
+                .expect("clap requires the main class unless -jar is given")
+                .replace('.', "/");
+            let classpath = ClassLoader::parse_classpath(opts.classpath.as_deref().unwrap_or("."));
+            let class_loader = ClassLoader::new(classpath, opts.strict)?;
+            (main_class, opts.args.clone(), class_loader)
+        }
+    };
 
     if opts.verbose {
+        let class = class_loader
+            .load(&main_class)?
+            .ok_or_else(|| format!("Could not find or load class {}", main_class.replace('/', ".")))?;
         println!("{:?}", class);
     } else {
-        let mut vm = VM::new(class);
-        vm.exec()?;
+        let mut vm = VM::new(class_loader);
+        if let Err(err) = vm.exec(&main_class, &args) {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
     }
 
     Ok(())
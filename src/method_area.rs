@@ -0,0 +1,39 @@
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use crate::class::Class;
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-2.html#jvms-2.5.4
+// The loaded classes by binary name, e.g. `com/foo/Bar`. Classes are never unloaded.
+#[derive(Default)]
+pub struct MethodArea {
+    classes: HashMap<String, Rc<Class>>,
+    // Superinterfaces that the class path does not have, such as `java/lang/Runnable` of the JDK.
+    // Only their names are known, so they contribute no members.
+    stubs: HashSet<String>,
+}
+
+impl MethodArea {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, binary_name: &str) -> Option<Rc<Class>> {
+        self.classes.get(binary_name).map(Rc::clone)
+    }
+
+    // Stubs count as loaded although `get` does not return them.
+    pub fn contains(&self, binary_name: &str) -> bool {
+        self.classes.contains_key(binary_name) || self.stubs.contains(binary_name)
+    }
+
+    pub fn insert(&mut self, binary_name: &str, class: Class) -> Rc<Class> {
+        let class = Rc::new(class);
+        self.classes.insert(binary_name.to_string(), Rc::clone(&class));
+        class
+    }
+
+    pub fn insert_stub(&mut self, binary_name: &str) {
+        self.stubs.insert(binary_name.to_string());
+    }
+}
@@ -1,6 +1,12 @@
-#[derive(Debug, Clone, Copy)]
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Item {
+    Null,
     Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
     Classref(usize),
     String(usize),
     Fieldref(usize),
@@ -8,21 +14,190 @@ pub enum Item {
     Arrayref(usize),
 }
 
+impl Item {
+    // long and double are category 2 computational types (JVMS 2.11.1): they take two local
+    // variable slots and count twice towards the operand stack depth.
+    pub fn is_category2(&self) -> bool {
+        matches!(self, Self::Long(_) | Self::Double(_))
+    }
+}
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-2.html#jvms-2.6.2
+// The depth is counted in units of the computational types, so a long or double counts as two.
 #[derive(Debug, Clone, Default)]
 pub struct OperandStack {
-    pub stack: Vec<Item>,
+    stack: Vec<Item>,
+    depth: usize,
+    max_stack: usize,
 }
 
 impl OperandStack {
-    pub fn new() -> Self {
-        Self { stack: vec![] }
+    pub fn new(max_stack: usize) -> Self {
+        Self {
+            stack: Vec::with_capacity(max_stack),
+            depth: 0,
+            max_stack,
+        }
+    }
+
+    pub fn push(&mut self, item: Item) -> Result<(), StackError> {
+        let depth = self.depth + if item.is_category2() { 2 } else { 1 };
+        if depth > self.max_stack {
+            return Err(StackError::Overflow {
+                max_stack: self.max_stack,
+            });
+        }
+        self.stack.push(item);
+        self.depth = depth;
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Result<Item, StackError> {
+        let item = self.stack.pop().ok_or(StackError::Underflow)?;
+        self.depth -= if item.is_category2() { 2 } else { 1 };
+        Ok(item)
+    }
+
+    // Empties the stack, e.g. before pushing an exception for its handler.
+    pub fn clear(&mut self) {
+        self.stack.clear();
+        self.depth = 0;
+    }
+
+    // Pops values that occupy exactly `slots` units of depth. The value on top comes first.
+    // A long or double that would have to be split is a type error (JVMS 2.11.1).
+    fn pop_slots(&mut self, slots: usize) -> Result<Vec<Item>, StackError> {
+        let mut items = vec![];
+        let mut popped = 0;
+        while popped < slots {
+            let item = self.pop()?;
+            popped += if item.is_category2() { 2 } else { 1 };
+            if popped > slots {
+                return Err(StackError::Type {
+                    expected: "category 1 value",
+                    found: item,
+                });
+            }
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    fn push_slots(&mut self, items: &[Item]) -> Result<(), StackError> {
+        items.iter().rev().try_for_each(|item| self.push(*item))
+    }
+
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-6.html#jvms-6.5.pop
+    // pop and pop2 discard the top one or two slots.
+    pub fn pop_n(&mut self, slots: usize) -> Result<(), StackError> {
+        self.pop_slots(slots)?;
+        Ok(())
+    }
+
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-6.html#jvms-6.5.dup
+    // Duplicates the top `slots` slots and inserts the copy `depth` slots further down, which covers
+    // every form of dup, dup_x1, dup_x2, dup2, dup2_x1 and dup2_x2.
+    pub fn dup(&mut self, slots: usize, depth: usize) -> Result<(), StackError> {
+        let values = self.pop_slots(slots)?;
+        let below = self.pop_slots(depth)?;
+        self.push_slots(&values)?;
+        self.push_slots(&below)?;
+        self.push_slots(&values)
+    }
+
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-6.html#jvms-6.5.swap
+    pub fn swap(&mut self) -> Result<(), StackError> {
+        let value1 = self.pop_slots(1)?;
+        let value2 = self.pop_slots(1)?;
+        self.push_slots(&value1)?;
+        self.push_slots(&value2)
+    }
+
+    pub fn pop_int(&mut self) -> Result<i32, StackError> {
+        match self.pop()? {
+            Item::Int(val) => Ok(val),
+            item => Err(StackError::Type {
+                expected: "int",
+                found: item,
+            }),
+        }
+    }
+
+    pub fn pop_long(&mut self) -> Result<i64, StackError> {
+        match self.pop()? {
+            Item::Long(val) => Ok(val),
+            item => Err(StackError::Type {
+                expected: "long",
+                found: item,
+            }),
+        }
     }
 
-    pub fn push(&mut self, item: Item) {
-        self.stack.push(item)
+    pub fn pop_float(&mut self) -> Result<f32, StackError> {
+        match self.pop()? {
+            Item::Float(val) => Ok(val),
+            item => Err(StackError::Type {
+                expected: "float",
+                found: item,
+            }),
+        }
     }
 
-    pub fn pop(&mut self) -> Option<Item> {
-        self.stack.pop()
+    pub fn pop_double(&mut self) -> Result<f64, StackError> {
+        match self.pop()? {
+            Item::Double(val) => Ok(val),
+            item => Err(StackError::Type {
+                expected: "double",
+                found: item,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StackError {
+    // Pushing would make the stack deeper than max_stack of the Code attribute.
+    Overflow { max_stack: usize },
+    // Popping from an empty stack.
+    Underflow,
+    // The value on top of the stack is not of the type the instruction operates on.
+    Type { expected: &'static str, found: Item },
+}
+
+impl fmt::Display for StackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow { max_stack } => write!(f, "operand stack overflow (max_stack: {})", max_stack)?,
+            Self::Underflow => write!(f, "operand stack underflow")?,
+            Self::Type { expected, found } => write!(f, "expected {} on operand stack, found {:?}", expected, found)?,
+        }
+        Ok(())
+    }
+}
+
+// A `StackError` with the method and the pc of the instruction that caused it.
+#[derive(Debug)]
+pub struct OperandStackError {
+    pub method: String,
+    pub pc: usize,
+    pub error: StackError,
+}
+
+impl OperandStackError {
+    pub fn new(method: &str, pc: usize, error: StackError) -> Self {
+        Self {
+            method: method.to_string(),
+            pc,
+            error,
+        }
+    }
+}
+
+impl std::error::Error for OperandStackError {}
+
+impl fmt::Display for OperandStackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} in {} at pc {}", self.error, self.method, self.pc)?;
+        Ok(())
     }
 }
@@ -1,50 +1,124 @@
+mod native;
+
 use std::collections::HashMap;
+use std::iter;
+use std::rc::Rc;
 
-use crate::class::constant_pool::ConstantPoolInfo;
+use crate::class::attribute::code::CodeAttribute;
+use crate::class::constant_pool::{ConstantPool, ConstantPoolInfo};
+use crate::class::descriptor::{FieldType, MethodDescriptor};
 use crate::class::method::MethodInfo;
 use crate::class::Class;
+use crate::class_loader::ClassLoader;
+use crate::exception::{builtin_super_class, ExecError, Throwable, UncaughtException};
+use crate::heap::{default_value, Array, Field, Heap, Object};
 use crate::instruction::Instruction;
-use crate::operand_stack::{Item, OperandStack};
-
-#[macro_export]
-macro_rules! get_constant_pool {
-    ($cp_pool: expr, $index: expr, $expect: tt) => {
-        match &$cp_pool.get(($index - 1).into()).unwrap().info {
-            $expect(val) => val,
-            _v => unreachable!(),
-        }
-    };
-}
-
-type LocalVariable = HashMap<usize, Item>;
+use crate::local_variable::LocalVariable;
+use crate::method_area::MethodArea;
+use crate::operand_stack::{Item, OperandStack, OperandStackError, StackError};
+use native::NativeMethod;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Clone)]
 struct Frame {
+    // The class that declares the method, whose constant pool the instructions refer to.
+    pub class: Rc<Class>,
+    // The index of the method in the class.
+    pub method: usize,
+    // The instruction to execute next. A frame that has invoked a method stays on the invoke
+    // instruction until the method returns, so that exceptions are looked up at it.
     pub pc: usize,
     pub local_variable: LocalVariable,
     pub operand_stack: OperandStack,
 }
 
 impl Frame {
-    pub fn new(local_variable: LocalVariable) -> Self {
+    pub fn new(class: Rc<Class>, method: usize, max_locals: usize, max_stack: usize) -> Self {
         Self {
+            class,
+            method,
             pc: 0,
-            local_variable,
-            operand_stack: OperandStack::new(),
+            local_variable: LocalVariable::new(max_locals),
+            operand_stack: OperandStack::new(max_stack),
         }
     }
 }
 
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-5.html#jvms-5.5
+// A class without a state has not been initialized yet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum InitState {
+    InProgress,
+    Initialized,
+    Erroneous,
+}
+
+// How the method of the frame on top of the stack stops running.
+enum Completion {
+    // It invoked a method, whose frame is now on top of it.
+    Invoke,
+    // It returned, with the value unless it is void.
+    Return(Option<Item>),
+}
+
+// The method an invocation selects.
+#[derive(Clone)]
+enum Callee {
+    // The class that declares the method and the index of the method in it.
+    Java(Rc<Class>, usize),
+    Native(NativeMethod),
+}
+
+// Classes that are implemented by the VM instead of being loaded from the class path.
+const BUILTIN_CLASSES: &[&str] = &[
+    "java/lang/Object",
+    "java/lang/String",
+    "java/lang/System",
+    "java/io/PrintStream",
+];
+
+fn is_builtin_class(class_name: &str) -> bool {
+    BUILTIN_CLASSES.contains(&class_name) || builtin_super_class(class_name).is_some()
+}
+
+// https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-2.html#jvms-2.5.2
+// The number of frames the Java Virtual Machine stack holds. Invoking one more method throws
+// StackOverflowError.
+const MAX_FRAMES: usize = 10_000;
+
+fn code_attribute(method: &MethodInfo) -> Result<&CodeAttribute, ExecError> {
+    method
+        .code_attribute()
+        .first()
+        .copied()
+        .ok_or_else(|| format!("{}{} has no Code attribute", method.name, method.descriptor).into())
+}
+
 pub struct VM {
-    class_info: Class,
+    class_loader: ClassLoader,
+    // Classes are shared so that a method can be executed while the VM is borrowed mutably.
+    method_area: MethodArea,
     frames: Vec<Frame>,
+    heap: Heap,
+    // The static fields of each class by binary name.
+    statics: HashMap<String, Vec<Field>>,
+    init_states: HashMap<String, InitState>,
+    // The methods selected by invokevirtual and invokeinterface for each receiver class, keyed by
+    // name and descriptor. Interface methods share the table with class methods because selection
+    // only depends on the receiver class.
+    dispatch_tables: HashMap<String, HashMap<(String, String), Callee>>,
 }
 
 impl VM {
-    pub fn new(class_info: Class) -> Self {
+    // Classes are loaded from the class path of `class_loader` when they are first referenced.
+    pub fn new(class_loader: ClassLoader) -> Self {
         VM {
-            class_info,
+            class_loader,
+            method_area: MethodArea::new(),
             frames: vec![],
+            heap: Heap::new(),
+            statics: HashMap::new(),
+            init_states: HashMap::new(),
+            dispatch_tables: HashMap::new(),
         }
     }
 
@@ -52,151 +126,1530 @@ impl VM {
         self.frames.last_mut().expect("A frame should be stacked.")
     }
 
-    pub fn exec(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        let mut local_variable = LocalVariable::new();
-        local_variable.insert(0, Item::Classref(self.class_info.super_class as usize));
-        let frame = Frame::new(local_variable);
-        self.frames.push(frame);
-        let main_method = self
-            .class_info
-            .methods
-            .iter()
-            .find(|&method| method.name == "main")
-            .expect("The main method was not found.")
-            .clone();
+    // The class of the method being executed.
+    fn current_class(&self) -> Rc<Class> {
+        Rc::clone(&self.frames.last().expect("A frame should be stacked.").class)
+    }
 
-        self.exec_method(&main_method);
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-5.html#jvms-5.2
+    // Runs `public static void main(String[])` of the class, e.g. `com/foo/Main`, with the arguments
+    // in a String[] like the java launcher does.
+    pub fn exec(&mut self, class_name: &str, args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+        let java_name = class_name.replace('/', ".");
+        let not_found = format!("Error: Could not find or load main class {}", java_name);
+        let class = match self.load_class(class_name).map(|()| self.find_class(class_name)) {
+            Ok(Some(class)) => class,
+            Err(ExecError::Throw(Item::Objectref(index))) => {
+                return Err(format!("{}\nCaused by: {}", not_found, self.throwable(index)?).into())
+            }
+            _ => return Err(not_found.into()),
+        };
+        let usage = "please define the main method as:\n   public static void main(String[] args)";
+        let main_method = match class.method_index("main", "([Ljava/lang/String;)V") {
+            Some(index) if class.methods[index].is_public() && class.methods[index].is_static() => index,
+            Some(index) if class.methods[index].is_public() => {
+                return Err(format!("Error: Main method is not static in class {}, {}", java_name, usage).into())
+            }
+            _ => return Err(format!("Error: Main method not found in class {}, {}", java_name, usage).into()),
+        };
+        let args = args.iter().map(|arg| self.heap.alloc_string(arg)).collect();
+        let args = self.heap.alloc_array(Array::new("[Ljava/lang/String;", args));
+
+        // The main class is initialized before main is invoked.
+        let result = self
+            .initialize(class_name)
+            .and_then(|()| self.run(&class, main_method, vec![args]));
+        match result {
+            Ok(_) => Ok(()),
+            Err(ExecError::Throw(Item::Objectref(index))) => {
+                Err(Box::new(UncaughtException::new(self.throwable(index)?)))
+            }
+            Err(err) => Err(Box::new(err)),
+        }
+    }
+
+    // Creates a Java exception and returns the error that makes the current instruction throw it.
+    fn throw(&mut self, class_name: &str, message: Option<&str>) -> ExecError {
+        let mut object = match self.new_object(class_name) {
+            Ok(object) => object,
+            Err(err) => return err,
+        };
+        if let (Some(message), Some(field)) = (message, object.field_mut("java/lang/Throwable", "detailMessage")) {
+            field.value = self.heap.intern(message);
+        }
+        ExecError::Throw(self.heap.alloc(object))
+    }
+
+    // Reads the class, the message and the cause of an exception object to report it.
+    fn throwable(&self, index: usize) -> Result<Throwable, String> {
+        let object = self.heap.get(index).ok_or("Invalid object reference")?;
+        let message = match object
+            .field("java/lang/Throwable", "detailMessage")
+            .map(|field| field.value)
+        {
+            Some(Item::String(index)) => self.heap.string(index),
+            _ => None,
+        };
+        let cause = match object.field("java/lang/Throwable", "cause").map(|field| field.value) {
+            Some(Item::Objectref(cause)) if cause != index => Some(self.throwable(cause)?),
+            _ => None,
+        };
+        Ok(Throwable::new(&object.class_name, message, cause))
+    }
 
+    // Returns the class if it has been loaded. Built-in classes are never loaded.
+    fn find_class(&self, class_name: &str) -> Option<Rc<Class>> {
+        self.method_area.get(class_name)
+    }
+
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-5.html#jvms-5.3.5
+    // Loads the class from the class path unless it has been loaded or is built in. Its superclass and
+    // superinterfaces are loaded first, so the whole hierarchy of a loaded class is in the method area.
+    fn load_class(&mut self, class_name: &str) -> Result<(), ExecError> {
+        self.load_from_classpath(class_name, false)
+    }
+
+    // Superinterfaces only matter to select default methods and to resolve fields and methods, so
+    // one that the class path does not have, e.g. `java/lang/Runnable` of the JDK, is recorded by
+    // name instead of failing the class that implements it.
+    fn load_superinterface(&mut self, interface_name: &str) -> Result<(), ExecError> {
+        self.load_from_classpath(interface_name, true)
+    }
+
+    fn load_from_classpath(&mut self, class_name: &str, stub_if_missing: bool) -> Result<(), ExecError> {
+        if self.method_area.contains(class_name) || is_builtin_class(class_name) {
+            return Ok(());
+        }
+        let class = match self.class_loader.load(class_name) {
+            Ok(Some(class)) => class,
+            Ok(None) if stub_if_missing => {
+                self.method_area.insert_stub(class_name);
+                return Ok(());
+            }
+            Ok(None) => return Err(self.throw("java/lang/NoClassDefFoundError", Some(class_name))),
+            Err(err) => {
+                let message = format!("{}: {}", class_name, err);
+                return Err(self.throw("java/lang/ClassFormatError", Some(&message)));
+            }
+        };
+        match class.this_class_name() {
+            Some(name) if name == class_name => {}
+            name => {
+                let message = format!("{} (wrong name: {})", class_name, name.unwrap_or_default());
+                return Err(self.throw("java/lang/NoClassDefFoundError", Some(&message)));
+            }
+        }
+
+        if let Some(super_class) = class.cp_info.class_name(class.super_class) {
+            self.load_class(&super_class)?;
+        }
+        for interface in &class.interfaces {
+            self.load_superinterface(interface)?;
+        }
+        self.method_area.insert(class_name, class);
         Ok(())
     }
 
-    fn exec_method(&mut self, method: &MethodInfo) {
-        for code_attr in method.code_attribute() {
-            for inst in &code_attr.instructions {
-                if let Err(msg) = self.exec_per_inst(inst) {
-                    panic!(msg)
+    fn super_class_name(&self, class_name: &str) -> Option<String> {
+        match self.find_class(class_name) {
+            Some(class) => class.cp_info.class_name(class.super_class),
+            None => builtin_super_class(class_name).map(|name| name.to_string()),
+        }
+    }
+
+    fn is_subclass_of(&self, class_name: &str, target: &str) -> bool {
+        let mut current = Some(class_name.to_string());
+        while let Some(name) = current {
+            if name == target {
+                return true;
+            }
+            current = self.super_class_name(&name);
+        }
+        false
+    }
+
+    // Lays out the instance fields of the class and all its superclasses with their default values.
+    // The object is not on the heap until it is passed to `Heap::alloc`.
+    fn new_object(&self, class_name: &str) -> Result<Object, ExecError> {
+        let mut classes = vec![];
+        let mut current = Some(class_name.to_string());
+        while let Some(name) = current {
+            current = self.super_class_name(&name);
+            classes.push(name);
+        }
+
+        let mut fields = vec![];
+        for name in classes.iter().rev() {
+            match self.find_class(name) {
+                Some(class) => {
+                    fields.extend(
+                        class
+                            .fields
+                            .iter()
+                            .filter(|field| !field.is_static())
+                            .map(|field| Field {
+                                class_name: name.clone(),
+                                name: field.name.clone(),
+                                descriptor: field.descriptor.clone(),
+                                value: default_value(&field.field_type),
+                            }),
+                    )
                 }
+                // The detail message and the cause are the only fields of the built-in exceptions.
+                None if name == "java/lang/Throwable" => fields.extend([
+                    Field {
+                        class_name: name.clone(),
+                        name: "detailMessage".to_string(),
+                        descriptor: "Ljava/lang/String;".to_string(),
+                        value: Item::Null,
+                    },
+                    Field {
+                        class_name: name.clone(),
+                        name: "cause".to_string(),
+                        descriptor: "Ljava/lang/Throwable;".to_string(),
+                        value: Item::Null,
+                    },
+                ]),
+                None if is_builtin_class(name) => {}
+                None => return Err(format!("Class {} is not found", name).into()),
             }
         }
+        Ok(Object::new(class_name, fields))
     }
 
-    fn exec_per_inst(&mut self, inst: &Instruction) -> Result<(), String> {
-        use ConstantPoolInfo::*;
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-5.html#jvms-5.4.3.2
+    // Looks for the class or interface that declares the field: the one the Fieldref names, then its
+    // direct superinterfaces recursively, and then its superclass in the same way.
+    fn resolve_field(&self, class_name: &str, name: &str, descriptor: &str) -> Result<String, ExecError> {
+        self.field_declarer(class_name, name, descriptor)
+            .ok_or_else(|| format!("Field {}.{}:{} is not found", class_name, name, descriptor).into())
+    }
+
+    fn field_declarer(&self, class_name: &str, name: &str, descriptor: &str) -> Option<String> {
+        let class = self.find_class(class_name);
+        let declared = match &class {
+            Some(class) => class
+                .fields
+                .iter()
+                .any(|field| field.name == name && field.descriptor == descriptor),
+            None => {
+                (class_name == "java/lang/Throwable" && (name == "detailMessage" || name == "cause"))
+                    || (class_name == "java/lang/System" && name == "out")
+            }
+        };
+        if declared {
+            return Some(class_name.to_string());
+        }
+        if let Some(class) = &class {
+            for interface in &class.interfaces {
+                if let Some(declarer) = self.field_declarer(interface, name, descriptor) {
+                    return Some(declarer);
+                }
+            }
+        }
+        self.field_declarer(&self.super_class_name(class_name)?, name, descriptor)
+    }
+
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-5.html#jvms-5.4.2
+    // Creates the static fields of a class on first use, with the values of their ConstantValue
+    // attributes or else the default values.
+    fn static_field(&mut self, class_name: &str, name: &str) -> Result<&mut Field, ExecError> {
+        if !self.statics.contains_key(class_name) {
+            let fields = self.prepare_static_fields(class_name)?;
+            self.statics.insert(class_name.to_string(), fields);
+        }
+        self.statics
+            .get_mut(class_name)
+            .and_then(|fields| fields.iter_mut().find(|field| field.name == name))
+            .ok_or_else(|| format!("Static field {}.{} is not found", class_name, name).into())
+    }
+
+    fn prepare_static_fields(&mut self, class_name: &str) -> Result<Vec<Field>, ExecError> {
+        let class = match self.find_class(class_name) {
+            Some(class) => class,
+            // System.out is the only static field of the built-in classes.
+            None if class_name == "java/lang/System" => {
+                let out = self.heap.alloc(Object::new("java/io/PrintStream", vec![]));
+                return Ok(vec![Field {
+                    class_name: class_name.to_string(),
+                    name: "out".to_string(),
+                    descriptor: "Ljava/io/PrintStream;".to_string(),
+                    value: out,
+                }]);
+            }
+            None => return Err(format!("Class {} is not found", class_name).into()),
+        };
+        let mut fields = vec![];
+        for field in class.fields.iter().filter(|field| field.is_static()) {
+            let value = match field.constant_value() {
+                Some(constant_value) => self.constant(&class, constant_value.constantvalue_index)?,
+                None => default_value(&field.field_type),
+            };
+            fields.push(Field {
+                class_name: class_name.to_string(),
+                name: field.name.clone(),
+                descriptor: field.descriptor.clone(),
+                value,
+            });
+        }
+        Ok(fields)
+    }
+
+    // Resolves a Fieldref to the class that declares the field and the name of the field.
+    fn field_ref(&mut self, field_index: u16) -> Result<(String, String), ExecError> {
+        let class = self.current_class();
+        let cp_info = &class.cp_info;
+        let field_ref = match cp_info.get(field_index).map(|cp| &cp.info) {
+            Some(ConstantPoolInfo::FieldrefInfo(field_ref)) => field_ref,
+            _ => return Err(format!("Constant pool #{} is not a field", field_index).into()),
+        };
+        let class_name = cp_info
+            .class_name(field_ref.class_index)
+            .ok_or_else(|| format!("Constant pool #{} is not a class", field_ref.class_index))?;
+        let (name, descriptor) = cp_info.name_and_type(field_ref.name_and_type_index).ok_or_else(|| {
+            format!(
+                "Constant pool #{} is not a name and type",
+                field_ref.name_and_type_index
+            )
+        })?;
+        self.load_class(&class_name)?;
+        let declaring_class = self.resolve_field(&class_name, &name, &descriptor)?;
+        Ok((declaring_class, name))
+    }
+
+    // Looks for the handler of the exception in the exception table of the method (JVMS 2.10).
+    fn find_exception_handler(&self, code_attr: &CodeAttribute, pc: usize, exception: Item) -> Option<usize> {
+        let class_name = match exception {
+            Item::Objectref(index) => &self.heap.get(index)?.class_name,
+            _ => return None,
+        };
+        let class = self.frames.last()?.class.as_ref();
+        code_attr
+            .exception_table
+            .iter()
+            .find(|entry| {
+                (usize::from(entry.start_pc)..usize::from(entry.end_pc)).contains(&pc)
+                    && (entry.catch_type == 0
+                        || class
+                            .cp_info
+                            .class_name(entry.catch_type)
+                            .is_some_and(|catch_type| self.is_subclass_of(class_name, &catch_type)))
+            })
+            .map(|entry| usize::from(entry.handle_pc))
+    }
+
+    // The interpreter loop. It runs the frame at the depth `base` and every frame stacked on it, so a
+    // Java method invoking another one does not recurse in Rust, and returns what that frame returns.
+    fn execute(&mut self, base: usize) -> Result<Option<Item>, ExecError> {
+        loop {
+            let frame = self.frames.last().expect("A frame should be stacked.");
+            let class = Rc::clone(&frame.class);
+            match self.exec_method(&class.methods[frame.method]) {
+                Ok(Completion::Invoke) => {}
+                Ok(Completion::Return(val)) => {
+                    self.frames.pop();
+                    if self.frames.len() < base {
+                        return Ok(val);
+                    }
+                    self.resume(val)?;
+                }
+                // An exception the method does not catch is thrown again by the invoke instruction of
+                // the caller.
+                Err(ExecError::Throw(exception)) => loop {
+                    self.frames.pop();
+                    if self.frames.len() < base {
+                        return Err(ExecError::Throw(exception));
+                    }
+                    if self.catch(exception)? {
+                        break;
+                    }
+                },
+                Err(err) => {
+                    self.frames.truncate(base - 1);
+                    return Err(err);
+                }
+            }
+        }
+    }
+
+    // Runs the method of the frame on top of the stack until it invokes a method or returns.
+    fn exec_method(&mut self, method: &MethodInfo) -> Result<Completion, ExecError> {
+        let code_attr = code_attribute(method)?;
+        let depth = self.frames.len();
+        loop {
+            let frame = self.get_current_mut_frame();
+            let pc = frame.pc;
+            let (inst, next_pc) = code_attr
+                .instructions
+                .fetch(pc)
+                .ok_or_else(|| format!("No instruction starts at pc {}", pc))?;
+            // Falls through to the next instruction unless the instruction itself updates pc.
+            frame.pc = next_pc;
+            let result = match inst {
+                Instruction::Return => return Ok(Completion::Return(None)),
+                Instruction::Ireturn
+                | Instruction::Lreturn
+                | Instruction::Freturn
+                | Instruction::Dreturn
+                | Instruction::Areturn => match self.return_value(inst) {
+                    Ok(val) => return Ok(Completion::Return(Some(val))),
+                    Err(err) => Err(err),
+                },
+                inst => self.exec_per_inst(inst, pc),
+            };
+            match result {
+                Ok(()) if self.frames.len() > depth => {
+                    self.frames[depth - 1].pc = pc;
+                    return Ok(Completion::Invoke);
+                }
+                Ok(()) => {}
+                Err(ExecError::Throw(exception)) => {
+                    self.get_current_mut_frame().pc = pc;
+                    if !self.catch(exception)? {
+                        return Err(ExecError::Throw(exception));
+                    }
+                }
+                Err(ExecError::Stack(err)) => {
+                    return Err(ExecError::StackFault(OperandStackError::new(&method.name, pc, err)))
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    // Jumps to the handler of the exception thrown at the pc of the frame on top of the stack, with
+    // the exception alone on the operand stack. Returns false if the method has no handler for it.
+    fn catch(&mut self, exception: Item) -> Result<bool, ExecError> {
+        let frame = self.frames.last().expect("A frame should be stacked.");
+        let class = Rc::clone(&frame.class);
+        let code_attr = code_attribute(&class.methods[frame.method])?;
+        let handler_pc = match self.find_exception_handler(code_attr, frame.pc, exception) {
+            Some(handler_pc) => handler_pc,
+            None => return Ok(false),
+        };
+        let frame = self.get_current_mut_frame();
+        frame.operand_stack.clear();
+        frame.operand_stack.push(exception)?;
+        frame.pc = handler_pc;
+        Ok(true)
+    }
+
+    // The caller continues after its invoke instruction with the return value on its operand stack.
+    fn resume(&mut self, val: Option<Item>) -> Result<(), ExecError> {
+        let class = self.current_class();
+        let frame = self.get_current_mut_frame();
+        let method = &class.methods[frame.method];
+        let pc = frame.pc;
+        let (_, next_pc) = code_attribute(method)?
+            .instructions
+            .fetch(pc)
+            .ok_or_else(|| format!("No instruction starts at pc {}", pc))?;
+        frame.pc = next_pc;
+        if let Some(val) = val {
+            frame
+                .operand_stack
+                .push(val)
+                .map_err(|err| ExecError::StackFault(OperandStackError::new(&method.name, pc, err)))?;
+        }
+        Ok(())
+    }
+
+    fn exec_per_inst(&mut self, inst: &Instruction, pc: usize) -> Result<(), ExecError> {
         match inst {
-            Instruction::Iconst3 => {
-                let mut frame = self.get_current_mut_frame();
-                frame.operand_stack.push(Item::Int(3));
-                frame.pc += 1;
-            }
-            Instruction::Iconst5 => {
-                let mut frame = self.get_current_mut_frame();
-                frame.operand_stack.push(Item::Int(5));
-                frame.pc += 1;
-            }
-            Instruction::Iload1 => {
-                let mut frame = self.get_current_mut_frame();
-                if let Some(val) = frame.local_variable.get(&1) {
-                    frame.operand_stack.push(*val);
-                } else {
-                    return Err("Variable is not set to avalue".into());
+            Instruction::Nop => {}
+            Instruction::AconstNull => self.get_current_mut_frame().operand_stack.push(Item::Null)?,
+            Instruction::IconstM1 => self.get_current_mut_frame().operand_stack.push(Item::Int(-1))?,
+            Instruction::Iconst0 => self.get_current_mut_frame().operand_stack.push(Item::Int(0))?,
+            Instruction::Iconst1 => self.get_current_mut_frame().operand_stack.push(Item::Int(1))?,
+            Instruction::Iconst2 => self.get_current_mut_frame().operand_stack.push(Item::Int(2))?,
+            Instruction::Iconst3 => self.get_current_mut_frame().operand_stack.push(Item::Int(3))?,
+            Instruction::Iconst4 => self.get_current_mut_frame().operand_stack.push(Item::Int(4))?,
+            Instruction::Iconst5 => self.get_current_mut_frame().operand_stack.push(Item::Int(5))?,
+            Instruction::Lconst0 => self.get_current_mut_frame().operand_stack.push(Item::Long(0))?,
+            Instruction::Lconst1 => self.get_current_mut_frame().operand_stack.push(Item::Long(1))?,
+            Instruction::Fconst0 => self.get_current_mut_frame().operand_stack.push(Item::Float(0.0))?,
+            Instruction::Fconst1 => self.get_current_mut_frame().operand_stack.push(Item::Float(1.0))?,
+            Instruction::Fconst2 => self.get_current_mut_frame().operand_stack.push(Item::Float(2.0))?,
+            Instruction::Dconst0 => self.get_current_mut_frame().operand_stack.push(Item::Double(0.0))?,
+            Instruction::Dconst1 => self.get_current_mut_frame().operand_stack.push(Item::Double(1.0))?,
+            Instruction::Bipush(val) => self
+                .get_current_mut_frame()
+                .operand_stack
+                .push(Item::Int((*val).into()))?,
+            Instruction::Sipush(val) => self
+                .get_current_mut_frame()
+                .operand_stack
+                .push(Item::Int((*val).into()))?,
+            Instruction::Iload(index)
+            | Instruction::Lload(index)
+            | Instruction::Fload(index)
+            | Instruction::Dload(index)
+            | Instruction::Aload(index) => self.load((*index).into())?,
+            Instruction::Istore(index)
+            | Instruction::Lstore(index)
+            | Instruction::Fstore(index)
+            | Instruction::Dstore(index)
+            | Instruction::Astore(index) => self.store((*index).into())?,
+            Instruction::Iload0
+            | Instruction::Lload0
+            | Instruction::Fload0
+            | Instruction::Dload0
+            | Instruction::Aload0 => self.load(0)?,
+            Instruction::Iload1
+            | Instruction::Lload1
+            | Instruction::Fload1
+            | Instruction::Dload1
+            | Instruction::Aload1 => self.load(1)?,
+            Instruction::Iload2
+            | Instruction::Lload2
+            | Instruction::Fload2
+            | Instruction::Dload2
+            | Instruction::Aload2 => self.load(2)?,
+            Instruction::Iload3
+            | Instruction::Lload3
+            | Instruction::Fload3
+            | Instruction::Dload3
+            | Instruction::Aload3 => self.load(3)?,
+            Instruction::Istore0
+            | Instruction::Lstore0
+            | Instruction::Fstore0
+            | Instruction::Dstore0
+            | Instruction::Astore0 => self.store(0)?,
+            Instruction::Istore1
+            | Instruction::Lstore1
+            | Instruction::Fstore1
+            | Instruction::Dstore1
+            | Instruction::Astore1 => self.store(1)?,
+            Instruction::Istore2
+            | Instruction::Lstore2
+            | Instruction::Fstore2
+            | Instruction::Dstore2
+            | Instruction::Astore2 => self.store(2)?,
+            Instruction::Istore3
+            | Instruction::Lstore3
+            | Instruction::Fstore3
+            | Instruction::Dstore3
+            | Instruction::Astore3 => self.store(3)?,
+            Instruction::Iinc(index, constant) => {
+                let frame = self.get_current_mut_frame();
+                match frame.local_variable.get((*index).into())? {
+                    Item::Int(val) => frame
+                        .local_variable
+                        .set((*index).into(), Item::Int(val.wrapping_add((*constant).into())))?,
+                    item => return Err(format!("Type Error: expected int, found {:?}", item).into()),
                 }
-                frame.pc += 1;
             }
-            Instruction::Iload2 => {
-                let mut frame = self.get_current_mut_frame();
-                if let Some(val) = frame.local_variable.get(&2) {
-                    frame.operand_stack.push(*val);
+            Instruction::Pop => self.get_current_mut_frame().operand_stack.pop_n(1)?,
+            Instruction::Pop2 => self.get_current_mut_frame().operand_stack.pop_n(2)?,
+            Instruction::Dup => self.get_current_mut_frame().operand_stack.dup(1, 0)?,
+            Instruction::DupX1 => self.get_current_mut_frame().operand_stack.dup(1, 1)?,
+            Instruction::DupX2 => self.get_current_mut_frame().operand_stack.dup(1, 2)?,
+            Instruction::Dup2 => self.get_current_mut_frame().operand_stack.dup(2, 0)?,
+            Instruction::Dup2X1 => self.get_current_mut_frame().operand_stack.dup(2, 1)?,
+            Instruction::Dup2X2 => self.get_current_mut_frame().operand_stack.dup(2, 2)?,
+            Instruction::Swap => self.get_current_mut_frame().operand_stack.swap()?,
+            Instruction::Iadd => self.binary_int(i32::wrapping_add)?,
+            Instruction::Ladd => self.binary_long(i64::wrapping_add)?,
+            Instruction::Fadd => self.binary_float(|v1, v2| v1 + v2)?,
+            Instruction::Dadd => self.binary_double(|v1, v2| v1 + v2)?,
+            Instruction::Isub => self.binary_int(i32::wrapping_sub)?,
+            Instruction::Lsub => self.binary_long(i64::wrapping_sub)?,
+            Instruction::Fsub => self.binary_float(|v1, v2| v1 - v2)?,
+            Instruction::Dsub => self.binary_double(|v1, v2| v1 - v2)?,
+            Instruction::Imul => self.binary_int(i32::wrapping_mul)?,
+            Instruction::Lmul => self.binary_long(i64::wrapping_mul)?,
+            Instruction::Fmul => self.binary_float(|v1, v2| v1 * v2)?,
+            Instruction::Dmul => self.binary_double(|v1, v2| v1 * v2)?,
+            Instruction::Idiv | Instruction::Irem => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let v2 = stack.pop_int()?;
+                let v1 = stack.pop_int()?;
+                if v2 == 0 {
+                    return Err(self.throw("java/lang/ArithmeticException", Some("/ by zero")));
+                }
+                // Integer.MIN_VALUE / -1 overflows and wraps instead of trapping.
+                let val = if let Instruction::Idiv = inst {
+                    v1.wrapping_div(v2)
                 } else {
-                    return Err("Variable is not set to avalue".into());
+                    v1.wrapping_rem(v2)
+                };
+                stack.push(Item::Int(val))?;
+            }
+            Instruction::Ldiv | Instruction::Lrem => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let v2 = stack.pop_long()?;
+                let v1 = stack.pop_long()?;
+                if v2 == 0 {
+                    return Err(self.throw("java/lang/ArithmeticException", Some("/ by zero")));
                 }
-                frame.pc += 1;
+                let val = if let Instruction::Ldiv = inst {
+                    v1.wrapping_div(v2)
+                } else {
+                    v1.wrapping_rem(v2)
+                };
+                stack.push(Item::Long(val))?;
             }
-            Instruction::Aload0 => {
-                let mut frame = self.get_current_mut_frame();
-                let val = frame.local_variable.get(&0).unwrap();
-                frame.operand_stack.push(*val);
-                frame.pc += 1;
+            Instruction::Fdiv => self.binary_float(|v1, v2| v1 / v2)?,
+            Instruction::Ddiv => self.binary_double(|v1, v2| v1 / v2)?,
+            // Rust's % on floating-point values truncates like C's fmod, which is what JVMS requires.
+            Instruction::Frem => self.binary_float(|v1, v2| v1 % v2)?,
+            Instruction::Drem => self.binary_double(|v1, v2| v1 % v2)?,
+            Instruction::Ineg => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_int()?;
+                stack.push(Item::Int(val.wrapping_neg()))?;
             }
-            Instruction::Istore1 => {
-                let mut frame = self.get_current_mut_frame();
-                let val = frame.operand_stack.pop().unwrap();
-                frame.local_variable.insert(1, val);
-                frame.pc += 1;
+            Instruction::Lneg => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_long()?;
+                stack.push(Item::Long(val.wrapping_neg()))?;
             }
-            Instruction::Istore2 => {
-                let mut frame = self.get_current_mut_frame();
-                let val = frame.operand_stack.pop().unwrap();
-                frame.local_variable.insert(2, val);
-                frame.pc += 1;
+            Instruction::Fneg => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_float()?;
+                stack.push(Item::Float(-val))?;
             }
-            Instruction::Iadd => {
-                let frame = self.get_current_mut_frame();
-                if let (Some(Item::Int(v2)), Some(Item::Int(v1))) =
-                    (frame.operand_stack.pop(), frame.operand_stack.pop())
-                {
-                    frame.operand_stack.push(Item::Int(v1 + v2))
-                } else {
-                    panic!("Type Error");
+            Instruction::Dneg => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_double()?;
+                stack.push(Item::Double(-val))?;
+            }
+            // wrapping_shl and wrapping_shr mask the shift distance by the bit width like JVMS does.
+            Instruction::Ishl => self.binary_int(|v1, v2| v1.wrapping_shl(v2 as u32))?,
+            Instruction::Ishr => self.binary_int(|v1, v2| v1.wrapping_shr(v2 as u32))?,
+            Instruction::Iushr => self.binary_int(|v1, v2| (v1 as u32).wrapping_shr(v2 as u32) as i32)?,
+            Instruction::Lshl => self.shift_long(|v1, v2| v1.wrapping_shl(v2 as u32))?,
+            Instruction::Lshr => self.shift_long(|v1, v2| v1.wrapping_shr(v2 as u32))?,
+            Instruction::Lushr => self.shift_long(|v1, v2| (v1 as u64).wrapping_shr(v2 as u32) as i64)?,
+            Instruction::Iand => self.binary_int(|v1, v2| v1 & v2)?,
+            Instruction::Land => self.binary_long(|v1, v2| v1 & v2)?,
+            Instruction::Ior => self.binary_int(|v1, v2| v1 | v2)?,
+            Instruction::Lor => self.binary_long(|v1, v2| v1 | v2)?,
+            Instruction::Ixor => self.binary_int(|v1, v2| v1 ^ v2)?,
+            Instruction::Lxor => self.binary_long(|v1, v2| v1 ^ v2)?,
+            // `as` casts from floating-point to integer saturate and map NaN to 0, and the other
+            // casts round to nearest, both as JVMS 2.8.3 specifies.
+            Instruction::I2l => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_int()?;
+                stack.push(Item::Long(val.into()))?;
+            }
+            Instruction::I2f => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_int()?;
+                stack.push(Item::Float(val as f32))?;
+            }
+            Instruction::I2d => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_int()?;
+                stack.push(Item::Double(val.into()))?;
+            }
+            Instruction::L2i => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_long()?;
+                stack.push(Item::Int(val as i32))?;
+            }
+            Instruction::L2f => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_long()?;
+                stack.push(Item::Float(val as f32))?;
+            }
+            Instruction::L2d => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_long()?;
+                stack.push(Item::Double(val as f64))?;
+            }
+            Instruction::F2i => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_float()?;
+                stack.push(Item::Int(val as i32))?;
+            }
+            Instruction::F2l => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_float()?;
+                stack.push(Item::Long(val as i64))?;
+            }
+            Instruction::F2d => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_float()?;
+                stack.push(Item::Double(val.into()))?;
+            }
+            Instruction::D2i => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_double()?;
+                stack.push(Item::Int(val as i32))?;
+            }
+            Instruction::D2l => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_double()?;
+                stack.push(Item::Long(val as i64))?;
+            }
+            Instruction::D2f => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_double()?;
+                stack.push(Item::Float(val as f32))?;
+            }
+            Instruction::I2b => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_int()?;
+                stack.push(Item::Int((val as i8).into()))?;
+            }
+            Instruction::I2c => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_int()?;
+                stack.push(Item::Int((val as u16).into()))?;
+            }
+            Instruction::I2s => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop_int()?;
+                stack.push(Item::Int((val as i16).into()))?;
+            }
+            Instruction::Lcmp => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let v2 = stack.pop_long()?;
+                let v1 = stack.pop_long()?;
+                stack.push(Item::Int(v1.cmp(&v2) as i32))?;
+            }
+            // The l and g variants differ only in the result for NaN.
+            Instruction::Fcmpl | Instruction::Fcmpg => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let v2 = stack.pop_float()?;
+                let v1 = stack.pop_float()?;
+                let nan = if let Instruction::Fcmpl = inst { -1 } else { 1 };
+                stack.push(Item::Int(v1.partial_cmp(&v2).map_or(nan, |ord| ord as i32)))?;
+            }
+            Instruction::Dcmpl | Instruction::Dcmpg => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let v2 = stack.pop_double()?;
+                let v1 = stack.pop_double()?;
+                let nan = if let Instruction::Dcmpl = inst { -1 } else { 1 };
+                stack.push(Item::Int(v1.partial_cmp(&v2).map_or(nan, |ord| ord as i32)))?;
+            }
+            Instruction::Invokespecial(method_index) => self.invoke_special(*method_index)?,
+            Instruction::InvokeVirtual(method_index) | Instruction::InvokeInterface(method_index, _) => {
+                self.invoke_virtual(*method_index)?
+            }
+            Instruction::InvokeStatic(method_index) => self.invoke_static(*method_index)?,
+            Instruction::Ldc(index) => self.ldc((*index).into(), false)?,
+            Instruction::LdcW(index) => self.ldc(*index, false)?,
+            Instruction::Ldc2W(index) => self.ldc(*index, true)?,
+            Instruction::GetStatic(field_index) => {
+                let (class_name, name) = self.field_ref(*field_index)?;
+                self.initialize(&class_name)?;
+                let val = self.static_field(&class_name, &name)?.value;
+                self.get_current_mut_frame().operand_stack.push(val)?;
+            }
+            Instruction::PutStatic(field_index) => {
+                let (class_name, name) = self.field_ref(*field_index)?;
+                self.initialize(&class_name)?;
+                let val = self.get_current_mut_frame().operand_stack.pop()?;
+                self.static_field(&class_name, &name)?.value = val;
+            }
+            // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-6.html#jvms-6.5.new
+            Instruction::New(class_index) => {
+                let class_name = self
+                    .current_class()
+                    .cp_info
+                    .class_name(*class_index)
+                    .ok_or_else(|| format!("Constant pool #{} is not a class", class_index))?;
+                self.initialize(&class_name)?;
+                let object = self.new_object(&class_name)?;
+                let objectref = self.heap.alloc(object);
+                self.get_current_mut_frame().operand_stack.push(objectref)?;
+            }
+            Instruction::GetField(field_index) => {
+                let (class_name, name) = self.field_ref(*field_index)?;
+                let index = match self.get_current_mut_frame().operand_stack.pop()? {
+                    Item::Objectref(index) => index,
+                    Item::Null => return Err(self.throw("java/lang/NullPointerException", None)),
+                    item => {
+                        return Err(StackError::Type {
+                            expected: "object",
+                            found: item,
+                        }
+                        .into())
+                    }
+                };
+                let val = self
+                    .heap
+                    .get(index)
+                    .and_then(|object| object.field(&class_name, &name))
+                    .ok_or_else(|| format!("Object has no field {}.{}", class_name, name))?
+                    .value;
+                self.get_current_mut_frame().operand_stack.push(val)?;
+            }
+            Instruction::PutField(field_index) => {
+                let (class_name, name) = self.field_ref(*field_index)?;
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = stack.pop()?;
+                let index = match stack.pop()? {
+                    Item::Objectref(index) => index,
+                    Item::Null => return Err(self.throw("java/lang/NullPointerException", None)),
+                    item => {
+                        return Err(StackError::Type {
+                            expected: "object",
+                            found: item,
+                        }
+                        .into())
+                    }
+                };
+                self.heap
+                    .get_mut(index)
+                    .and_then(|object| object.field_mut(&class_name, &name))
+                    .ok_or_else(|| format!("Object has no field {}.{}", class_name, name))?
+                    .value = val;
+            }
+            // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-6.html#jvms-6.5.arraylength
+            Instruction::Arraylength => {
+                let arrayref = self.get_current_mut_frame().operand_stack.pop()?;
+                let length = self.array(arrayref)?.elements.len();
+                self.get_current_mut_frame()
+                    .operand_stack
+                    .push(Item::Int(length as i32))?;
+            }
+            // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-6.html#jvms-6.5.newarray
+            Instruction::Newarray(atype) => {
+                let descriptor = match atype {
+                    4 => "[Z",
+                    5 => "[C",
+                    6 => "[F",
+                    7 => "[D",
+                    8 => "[B",
+                    9 => "[S",
+                    10 => "[I",
+                    11 => "[J",
+                    _ => return Err(format!("Invalid newarray type {}", atype).into()),
+                };
+                let count = self.get_current_mut_frame().operand_stack.pop_int()?;
+                if count < 0 {
+                    let message = count.to_string();
+                    return Err(self.throw("java/lang/NegativeArraySizeException", Some(&message)));
                 }
+                let field_type = FieldType::new(&descriptor[1..]).map_err(|err| err.to_string())?;
+                let elements = vec![default_value(&field_type); count as usize];
+                let arrayref = self.heap.alloc_array(Array::new(descriptor, elements));
+                self.get_current_mut_frame().operand_stack.push(arrayref)?;
             }
-            Instruction::Isub => {
-                let frame = self.get_current_mut_frame();
-                if let (Some(Item::Int(v2)), Some(Item::Int(v1))) =
-                    (frame.operand_stack.pop(), frame.operand_stack.pop())
-                {
-                    frame.operand_stack.push(Item::Int(v1 - v2))
+            // Elements are stored with the types they are loaded with, so every typed load is the same.
+            Instruction::Iaload
+            | Instruction::Laload
+            | Instruction::Faload
+            | Instruction::Daload
+            | Instruction::Aaload
+            | Instruction::Baload
+            | Instruction::Caload
+            | Instruction::Saload => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let index = stack.pop_int()?;
+                let arrayref = stack.pop()?;
+                let index = self.array_index(arrayref, index)?;
+                let val = self.array(arrayref)?.elements[index];
+                self.get_current_mut_frame().operand_stack.push(val)?;
+            }
+            // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-6.html#jvms-6.5.bastore
+            // bastore, castore and sastore truncate the int value to the element type, and bastore
+            // stores only the lowest bit into a boolean array.
+            Instruction::Iastore
+            | Instruction::Lastore
+            | Instruction::Fastore
+            | Instruction::Dastore
+            | Instruction::Bastore
+            | Instruction::Castore
+            | Instruction::Sastore => {
+                let stack = &mut self.get_current_mut_frame().operand_stack;
+                let val = match inst {
+                    Instruction::Lastore => Item::Long(stack.pop_long()?),
+                    Instruction::Fastore => Item::Float(stack.pop_float()?),
+                    Instruction::Dastore => Item::Double(stack.pop_double()?),
+                    _ => Item::Int(stack.pop_int()?),
+                };
+                let index = stack.pop_int()?;
+                let arrayref = stack.pop()?;
+                let index = self.array_index(arrayref, index)?;
+                let array = self.array_mut(arrayref)?;
+                let val = match (inst, val) {
+                    (Instruction::Bastore, Item::Int(val)) if array.descriptor == "[Z" => Item::Int(val & 1),
+                    (Instruction::Bastore, Item::Int(val)) => Item::Int((val as i8).into()),
+                    (Instruction::Castore, Item::Int(val)) => Item::Int((val as u16).into()),
+                    (Instruction::Sastore, Item::Int(val)) => Item::Int((val as i16).into()),
+                    (_, val) => val,
+                };
+                array.elements[index] = val;
+            }
+            Instruction::Ifeq(offset) => self.if_int(pc, *offset, |v| v == 0)?,
+            Instruction::Ifne(offset) => self.if_int(pc, *offset, |v| v != 0)?,
+            Instruction::Iflt(offset) => self.if_int(pc, *offset, |v| v < 0)?,
+            Instruction::Ifge(offset) => self.if_int(pc, *offset, |v| v >= 0)?,
+            Instruction::Ifgt(offset) => self.if_int(pc, *offset, |v| v > 0)?,
+            Instruction::Ifle(offset) => self.if_int(pc, *offset, |v| v <= 0)?,
+            Instruction::IfIcmpeq(offset) => self.if_icmp(pc, *offset, |v1, v2| v1 == v2)?,
+            Instruction::IfIcmpne(offset) => self.if_icmp(pc, *offset, |v1, v2| v1 != v2)?,
+            Instruction::IfIcmplt(offset) => self.if_icmp(pc, *offset, |v1, v2| v1 < v2)?,
+            Instruction::IfIcmpge(offset) => self.if_icmp(pc, *offset, |v1, v2| v1 >= v2)?,
+            Instruction::IfIcmpgt(offset) => self.if_icmp(pc, *offset, |v1, v2| v1 > v2)?,
+            Instruction::IfIcmple(offset) => self.if_icmp(pc, *offset, |v1, v2| v1 <= v2)?,
+            Instruction::IfAcmpeq(offset) => self.if_acmp(pc, *offset, |v1, v2| v1 == v2)?,
+            Instruction::IfAcmpne(offset) => self.if_acmp(pc, *offset, |v1, v2| v1 != v2)?,
+            Instruction::Ifnull(offset) => self.if_ref(pc, *offset, |v| v == Item::Null)?,
+            Instruction::Ifnonnull(offset) => self.if_ref(pc, *offset, |v| v != Item::Null)?,
+            Instruction::Goto(offset) => self.jump(pc, (*offset).into()),
+            Instruction::GotoW(offset) => self.jump(pc, *offset),
+            Instruction::Tableswitch {
+                default,
+                low,
+                high,
+                offsets,
+            } => {
+                let index = self.get_current_mut_frame().operand_stack.pop_int()?;
+                let offset = if index < *low || index > *high {
+                    *default
                 } else {
-                    panic!("Type Error");
-                }
-            }
-            Instruction::Invokespecial(_, _) => self.get_current_mut_frame().pc += 3,
-            Instruction::InvokeVirtual(_, method_index) => {
-                let method_ref = get_constant_pool!(self.class_info.cp_info, method_index, MethodrefInfo);
-                let name_and_type =
-                    get_constant_pool!(self.class_info.cp_info, method_ref.name_and_type_index, NameAndTypeInfo);
-                let method_name = get_constant_pool!(self.class_info.cp_info, name_and_type.name_index, Utf8Info);
-
-                match &*method_name.to_string() {
-                    "println" => {
-                        let utf8info = self.class_info.cp_info.utf8info();
-                        let mut frame = self.get_current_mut_frame();
-                        match frame.operand_stack.pop() {
-                            Some(Item::Int(index)) => println!("{}", index),
-                            Some(Item::String(index)) => println!("{}", utf8info.get(&(index as u16)).unwrap()),
-                            None => panic!(),
-                            _ => unimplemented!(),
+                    offsets[(index - low) as usize]
+                };
+                self.jump(pc, offset);
+            }
+            Instruction::Lookupswitch { default, pairs } => {
+                let key = self.get_current_mut_frame().operand_stack.pop_int()?;
+                // The pairs are sorted by key, so they can be searched in binary.
+                let offset = pairs
+                    .binary_search_by_key(&key, |(k, _)| *k)
+                    .map_or(*default, |i| pairs[i].1);
+                self.jump(pc, offset);
+            }
+            Instruction::Athrow => match self.get_current_mut_frame().operand_stack.pop()? {
+                Item::Null => return Err(self.throw("java/lang/NullPointerException", None)),
+                exception @ Item::Objectref(_) => return Err(ExecError::Throw(exception)),
+                item => {
+                    return Err(StackError::Type {
+                        expected: "reference",
+                        found: item,
+                    }
+                    .into())
+                }
+            },
+            inst => return Err(format!("{} is not implemented yet", inst).into()),
+        };
+        Ok(())
+    }
+
+    // Resolves a Methodref or an InterfaceMethodref to the class name, the method name and the descriptor.
+    fn method_ref(&mut self, method_index: u16) -> Result<(String, String, String), ExecError> {
+        use ConstantPoolInfo::*;
+        let class = self.current_class();
+        let cp_info = &class.cp_info;
+        let (class_index, name_and_type_index) = match cp_info.get(method_index).map(|cp| &cp.info) {
+            Some(MethodrefInfo(method_ref)) => (method_ref.class_index, method_ref.name_and_type_index),
+            Some(InterfaceMethodrefInfo(method_ref)) => (method_ref.class_index, method_ref.name_and_type_index),
+            _ => return Err(format!("Constant pool #{} is not a method", method_index).into()),
+        };
+        let class_name = cp_info
+            .class_name(class_index)
+            .ok_or_else(|| format!("Constant pool #{} is not a class", class_index))?;
+        let (name, descriptor) = cp_info
+            .name_and_type(name_and_type_index)
+            .ok_or_else(|| format!("Constant pool #{} is not a name and type", name_and_type_index))?;
+        self.load_class(&class_name)?;
+        Ok((class_name, name, descriptor))
+    }
+
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-6.html#jvms-6.5.invokestatic
+    fn invoke_static(&mut self, method_index: u16) -> Result<(), ExecError> {
+        let (class_name, name, descriptor) = self.method_ref(method_index)?;
+        let (class, index) = match self.resolve_method(&class_name, &name, &descriptor)? {
+            Some(Callee::Java(class, index)) if class.methods[index].is_static() => (class, index),
+            Some(_) => {
+                let message = format!(
+                    "Expected static method {}.{}{}",
+                    class_name.replace('/', "."),
+                    name,
+                    descriptor
+                );
+                return Err(self.throw("java/lang/IncompatibleClassChangeError", Some(&message)));
+            }
+            None => return Err(format!("{}.{}{} is not implemented yet", class_name, name, descriptor).into()),
+        };
+        // The class that declares the method is initialized, not a subclass it is invoked through.
+        if let Some(declaring_class) = class.this_class_name() {
+            self.initialize(&declaring_class)?;
+        }
+        self.invoke(&class, index)
+    }
+
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-5.html#jvms-5.4.3.3
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-5.html#jvms-5.4.3.4
+    // Looks for the method in the class the Methodref names and its superclasses, and then in its
+    // superinterfaces. `None` means that it is not found, but a JDK class or interface that rjvm
+    // implements only partly may declare it.
+    fn resolve_method(&mut self, class_name: &str, name: &str, descriptor: &str) -> Result<Option<Callee>, ExecError> {
+        let mut in_jdk = false;
+        let mut current = Some(class_name.to_string());
+        while let Some(class) = current {
+            match self.find_class(&class) {
+                Some(class) => {
+                    let found = class
+                        .methods
+                        .iter()
+                        .position(|method| method.name == name && method.descriptor == descriptor);
+                    if let Some(index) = found {
+                        return Ok(Some(Callee::Java(class, index)));
+                    }
+                }
+                None => {
+                    if let Some(native) = native::find(&class, name, descriptor) {
+                        return Ok(Some(Callee::Native(native)));
+                    }
+                    in_jdk |= native::may_declare(&class, name, descriptor);
+                }
+            }
+            current = self.super_class_or_object(&class);
+        }
+
+        // A default method is preferred to an abstract one, and otherwise any of them is chosen.
+        let mut abstract_method = None;
+        for interface in self.superinterfaces(class_name) {
+            let class = match self.find_class(&interface) {
+                Some(class) => class,
+                None => {
+                    in_jdk = true;
+                    continue;
+                }
+            };
+            let found = class.methods.iter().position(|method| {
+                method.name == name && method.descriptor == descriptor && !method.is_static() && !method.is_private()
+            });
+            match found {
+                Some(index) if !class.methods[index].is_abstract() => return Ok(Some(Callee::Java(class, index))),
+                Some(index) => abstract_method = abstract_method.or(Some(Callee::Java(class, index))),
+                None => {}
+            }
+        }
+        match abstract_method {
+            Some(callee) => Ok(Some(callee)),
+            None if in_jdk => Ok(None),
+            None => {
+                let message = format!("{}.{}{}", class_name.replace('/', "."), name, descriptor);
+                Err(self.throw("java/lang/NoSuchMethodError", Some(&message)))
+            }
+        }
+    }
+
+    // Built-in classes other than exceptions have no superclass recorded, and it is Object.
+    fn super_class_or_object(&self, class_name: &str) -> Option<String> {
+        match self.super_class_name(class_name) {
+            Some(super_class) => Some(super_class),
+            None if class_name != "java/lang/Object" => Some("java/lang/Object".to_string()),
+            None => None,
+        }
+    }
+
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-6.html#jvms-6.5.invokevirtual
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-6.html#jvms-6.5.invokeinterface
+    fn invoke_virtual(&mut self, method_index: u16) -> Result<(), ExecError> {
+        let (class_name, name, descriptor) = self.method_ref(method_index)?;
+        let method_descriptor = MethodDescriptor::new(&descriptor).map_err(|err| err.to_string())?;
+        let resolved = self.resolve_method(&class_name, &name, &descriptor)?;
+        let args = self.pop_receiver_and_args(&method_descriptor)?;
+
+        let receiver_class = match args[0] {
+            Item::Objectref(index) => self
+                .heap
+                .get(index)
+                .ok_or("Invalid object reference")?
+                .class_name
+                .clone(),
+            Item::String(_) => "java/lang/String".to_string(),
+            item => {
+                return Err(StackError::Type {
+                    expected: "object",
+                    found: item,
+                }
+                .into())
+            }
+        };
+
+        // A private method is never overridden, so it is selected as resolved.
+        let callee = match resolved {
+            Some(Callee::Java(class, index)) if class.methods[index].is_static() => {
+                let message = format!(
+                    "Expecting non-static method {}.{}{}",
+                    class_name.replace('/', "."),
+                    name,
+                    descriptor
+                );
+                return Err(self.throw("java/lang/IncompatibleClassChangeError", Some(&message)));
+            }
+            Some(Callee::Java(class, index)) if class.methods[index].is_private() => Callee::Java(class, index),
+            _ => self.select_method(&receiver_class, &name, &descriptor)?,
+        };
+        self.call(callee, &method_descriptor, args)
+    }
+
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-6.html#jvms-6.5.invokespecial
+    fn invoke_special(&mut self, method_index: u16) -> Result<(), ExecError> {
+        let (class_name, name, descriptor) = self.method_ref(method_index)?;
+        let method_descriptor = MethodDescriptor::new(&descriptor).map_err(|err| err.to_string())?;
+        let resolved = self.resolve_method(&class_name, &name, &descriptor)?;
+        let not_found = format!("{}.{}{}", class_name.replace('/', "."), name, descriptor);
+        if let Some(Callee::Java(class, index)) = &resolved {
+            if class.methods[*index].is_static() {
+                let message = format!("Expecting non-static method {}", not_found);
+                return Err(self.throw("java/lang/IncompatibleClassChangeError", Some(&message)));
+            }
+        }
+        let args = self.pop_receiver_and_args(&method_descriptor)?;
+
+        // `super.m()` in a subclass looks up the method from the direct superclass of the current
+        // class. ACC_SUPER is treated as always set, as the JVM does since Java SE 8.
+        let current_class = self
+            .current_class()
+            .this_class_name()
+            .ok_or("The name of the class is not found")?;
+        let callee =
+            if name != "<init>" && class_name != current_class && self.is_subclass_of(&current_class, &class_name) {
+                let super_class = self
+                    .super_class_name(&current_class)
+                    .ok_or_else(|| format!("{} has no superclass", current_class))?;
+                self.lookup_method(&super_class, &name, &descriptor)?
+            } else {
+                // Instance initialization methods and the other methods are invoked as resolved.
+                match resolved {
+                    Some(Callee::Java(class, index)) if class.methods[index].is_abstract() => {
+                        return Err(self.throw("java/lang/AbstractMethodError", Some(&not_found)))
+                    }
+                    Some(callee) => callee,
+                    None => return Err(format!("{} is not implemented yet", not_found).into()),
+                }
+            };
+        self.call(callee, &method_descriptor, args)
+    }
+
+    // Pops the receiver and the arguments of an instance method, throwing NullPointerException when
+    // the receiver is null.
+    fn pop_receiver_and_args(&mut self, descriptor: &MethodDescriptor) -> Result<Vec<Item>, ExecError> {
+        let caller_stack = &mut self.get_current_mut_frame().operand_stack;
+        let mut args = (0..=descriptor.parameters.len())
+            .map(|_| caller_stack.pop())
+            .collect::<Result<Vec<_>, _>>()?;
+        args.reverse();
+        if let Item::Null = args[0] {
+            return Err(self.throw("java/lang/NullPointerException", None));
+        }
+        Ok(args)
+    }
+
+    // Pushes the frame of the selected method with `this` in local 0. A native method runs right away
+    // and its return value is pushed onto the caller's stack.
+    fn call(&mut self, callee: Callee, descriptor: &MethodDescriptor, args: Vec<Item>) -> Result<(), ExecError> {
+        match callee {
+            Callee::Java(class, index) => self.push_frame(&class, index, args)?,
+            Callee::Native(native) => {
+                if let Some(val) = native(self, descriptor, &args)? {
+                    self.get_current_mut_frame().operand_stack.push(val)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn select_method(&mut self, class_name: &str, name: &str, descriptor: &str) -> Result<Callee, ExecError> {
+        let key = (name.to_string(), descriptor.to_string());
+        if let Some(callee) = self.dispatch_tables.get(class_name).and_then(|table| table.get(&key)) {
+            return Ok(callee.clone());
+        }
+        let callee = self.lookup_method(class_name, name, descriptor)?;
+        self.dispatch_tables
+            .entry(class_name.to_string())
+            .or_default()
+            .insert(key, callee.clone());
+        Ok(callee)
+    }
+
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-5.html#jvms-5.4.6
+    fn lookup_method(&mut self, class_name: &str, name: &str, descriptor: &str) -> Result<Callee, ExecError> {
+        let not_found = format!("{}.{}{}", class_name.replace('/', "."), name, descriptor);
+
+        // The receiver class and then its superclasses. Built-in classes have native methods only, so
+        // a JDK method that rjvm does not implement is not an error of the program.
+        let mut in_jdk = false;
+        let mut current = Some(class_name.to_string());
+        while let Some(class) = current {
+            match self.find_class(&class) {
+                Some(class) => {
+                    let found = class.methods.iter().position(|method| {
+                        method.name == name
+                            && method.descriptor == descriptor
+                            && !method.is_static()
+                            && !method.is_private()
+                    });
+                    if let Some(index) = found {
+                        if class.methods[index].is_abstract() {
+                            return Err(self.throw("java/lang/AbstractMethodError", Some(&not_found)));
                         }
-                        frame.pc += 3;
+                        return Ok(Callee::Java(class, index));
+                    }
+                }
+                None => {
+                    if let Some(native) = native::find(&class, name, descriptor) {
+                        return Ok(Callee::Native(native));
                     }
-                    _ => unimplemented!(),
+                    in_jdk |= native::may_declare(&class, name, descriptor);
                 }
             }
-            Instruction::Ldc(index) => {
-                // TODO: remove unwrap()
-                let constant_pool = self.class_info.cp_info.get((index - 1).into()).unwrap();
-                let val = match constant_pool.info {
-                    ConstantPoolInfo::StringInfo(string_info) => string_info.bytes,
-                    _ => unimplemented!(),
-                };
-                let mut frame = self.get_current_mut_frame();
-                frame.operand_stack.push(Item::String(val.into()));
-                frame.pc += 2;
-            }
-            Instruction::Return => self.get_current_mut_frame().pc += 1,
-            Instruction::GetStatic(_, _) => {
-                // TODO: unimplemented!
-                // let index1 = code_attr.code.get(frame.pc + 1).unwrap();
-                // let index2 = code_attr.code.get(frame.pc + 2).unwrap();
-                // dbg!(index1, index2);
-                // let symbol1 = self.class_info.cp_info.get(*index1 as usize + 1).unwrap();
-                // let symbol2 = self.class_info.cp_info.get(*index2 as usize + 1).unwrap();
-                // println!("{}", symbol1);
-                // println!("{}", symbol2);
-                let mut frame = self.get_current_mut_frame();
-                frame.pc += 3;
-                frame.operand_stack.push(Item::Int(0));
+            current = self.super_class_or_object(&class);
+        }
+
+        // Otherwise the maximally-specific default method of the superinterfaces, which must be unique.
+        let mut candidates = vec![];
+        for interface in self.superinterfaces(class_name) {
+            let class = match self.find_class(&interface) {
+                Some(class) => class,
+                None => {
+                    in_jdk = true;
+                    continue;
+                }
+            };
+            let found = class.methods.iter().position(|method| {
+                method.name == name
+                    && method.descriptor == descriptor
+                    && !method.is_static()
+                    && !method.is_private()
+                    && !method.is_abstract()
+            });
+            if let Some(index) = found {
+                candidates.push((interface, Callee::Java(class, index)));
+            }
+        }
+        let maximally_specific: Vec<&Callee> = candidates
+            .iter()
+            .filter(|(interface, _)| {
+                !candidates
+                    .iter()
+                    .any(|(other, _)| other != interface && self.superinterfaces(other).contains(interface))
+            })
+            .map(|(_, callee)| callee)
+            .collect();
+        match maximally_specific.as_slice() {
+            [callee] => Ok((*callee).clone()),
+            [] if in_jdk => Err(format!("{} is not implemented yet", not_found).into()),
+            [] => Err(self.throw("java/lang/AbstractMethodError", Some(&not_found))),
+            _ => Err(self.throw("java/lang/IncompatibleClassChangeError", Some(&not_found))),
+        }
+    }
+
+    // All the interfaces that the class or interface implements or extends, directly or not.
+    fn superinterfaces(&self, class_name: &str) -> Vec<String> {
+        let mut interfaces: Vec<String> = vec![];
+        let mut pending = vec![class_name.to_string()];
+        while let Some(name) = pending.pop() {
+            let class = match self.find_class(&name) {
+                Some(class) => class,
+                None => continue,
+            };
+            for interface in &class.interfaces {
+                if !interfaces.contains(interface) {
+                    interfaces.push(interface.clone());
+                    pending.push(interface.clone());
+                }
+            }
+            if let Some(super_class) = class.cp_info.class_name(class.super_class) {
+                pending.push(super_class);
             }
+        }
+        interfaces
+    }
+
+    // Pops the arguments from the operand stack of the caller into the local variables of a new frame
+    // (JVMS 2.6.1). The method runs once the frame is on top of the stack.
+    fn invoke(&mut self, class: &Rc<Class>, index: usize) -> Result<(), ExecError> {
+        let caller_stack = &mut self.get_current_mut_frame().operand_stack;
+        let mut args = (0..class.methods[index].method_descriptor.parameters.len())
+            .map(|_| caller_stack.pop())
+            .collect::<Result<Vec<_>, _>>()?;
+        args.reverse();
+        self.push_frame(class, index, args)
+    }
+
+    // Stacks a new frame whose local variables start with the arguments.
+    fn push_frame(&mut self, class: &Rc<Class>, index: usize, args: Vec<Item>) -> Result<(), ExecError> {
+        if self.frames.len() >= MAX_FRAMES {
+            return Err(self.throw("java/lang/StackOverflowError", None));
+        }
+        let method = &class.methods[index];
+        let code_attr = code_attribute(method)?;
+        // `this` takes local 0 of an instance method, and each parameter then takes the slots of its
+        // type in the descriptor (JVMS 2.6.1).
+        let descriptor = &method.method_descriptor;
+        let receiver = if method.is_static() { 0 } else { 1 };
+        if receiver + descriptor.parameter_slots() > code_attr.max_locals.into() {
+            return Err(format!(
+                "{}{} has fewer local variables than its parameters",
+                method.name, method.descriptor
+            )
+            .into());
+        }
+        let mut frame = Frame::new(
+            Rc::clone(class),
+            index,
+            code_attr.max_locals.into(),
+            code_attr.max_stack.into(),
+        );
+        let slots = iter::repeat_n(1, receiver).chain(descriptor.parameters.iter().map(FieldType::slots));
+        let mut local = 0;
+        for (arg, slots) in args.into_iter().zip(slots) {
+            frame.local_variable.set(local, arg)?;
+            local += slots;
+        }
+        self.frames.push(frame);
+        Ok(())
+    }
+
+    // Runs the method to its completion, for the main method and class initialization.
+    fn run(&mut self, class: &Rc<Class>, index: usize, args: Vec<Item>) -> Result<Option<Item>, ExecError> {
+        self.push_frame(class, index, args)?;
+        self.execute(self.frames.len())
+    }
+
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-5.html#jvms-5.5
+    // Initializes the class the first time it is used: its superclass first, then its <clinit>.
+    fn initialize(&mut self, class_name: &str) -> Result<(), ExecError> {
+        match self.init_states.get(class_name) {
+            // A class being initialized is treated as initialized by the initialization itself.
+            Some(InitState::InProgress) | Some(InitState::Initialized) => return Ok(()),
+            Some(InitState::Erroneous) => {
+                let message = format!("Could not initialize class {}", class_name.replace('/', "."));
+                return Err(self.throw("java/lang/NoClassDefFoundError", Some(&message)));
+            }
+            None => {}
+        }
+        self.load_class(class_name)?;
+        let class = match self.find_class(class_name) {
+            Some(class) => class,
+            None => {
+                self.init_states.insert(class_name.to_string(), InitState::Initialized);
+                return Ok(());
+            }
+        };
+
+        self.init_states.insert(class_name.to_string(), InitState::InProgress);
+        let mut result = match class.cp_info.class_name(class.super_class) {
+            Some(super_class) => self.initialize(&super_class),
+            None => Ok(()),
+        };
+        if let (Ok(()), Some(clinit)) = (&result, class.method_index("<clinit>", "()V")) {
+            result = self.run(&class, clinit, vec![]).map(|_| ());
+        }
+
+        match result {
+            Ok(()) => {
+                self.init_states.insert(class_name.to_string(), InitState::Initialized);
+                Ok(())
+            }
+            Err(err) => {
+                self.init_states.insert(class_name.to_string(), InitState::Erroneous);
+                match err {
+                    // An exception that is not an Error is wrapped in ExceptionInInitializerError.
+                    ExecError::Throw(Item::Objectref(index))
+                        if !self
+                            .heap
+                            .get(index)
+                            .is_some_and(|exception| self.is_subclass_of(&exception.class_name, "java/lang/Error")) =>
+                    {
+                        let error = self.throw("java/lang/ExceptionInInitializerError", None);
+                        if let ExecError::Throw(Item::Objectref(error_index)) = error {
+                            if let Some(cause) = self
+                                .heap
+                                .get_mut(error_index)
+                                .and_then(|object| object.field_mut("java/lang/Throwable", "cause"))
+                            {
+                                cause.value = Item::Objectref(index);
+                            }
+                        }
+                        Err(error)
+                    }
+                    err => Err(err),
+                }
+            }
+        }
+    }
+
+    // Throws NullPointerException if the reference is null.
+    fn array(&mut self, arrayref: Item) -> Result<&Array, ExecError> {
+        match arrayref {
+            Item::Arrayref(index) => Ok(self.heap.array(index).ok_or("Invalid array reference")?),
+            Item::Null => Err(self.throw("java/lang/NullPointerException", None)),
+            item => Err(StackError::Type {
+                expected: "array",
+                found: item,
+            }
+            .into()),
+        }
+    }
+
+    fn array_mut(&mut self, arrayref: Item) -> Result<&mut Array, ExecError> {
+        match arrayref {
+            Item::Arrayref(index) => Ok(self.heap.array_mut(index).ok_or("Invalid array reference")?),
+            _ => Err(format!("Expected an array reference, but found {:?}", arrayref).into()),
+        }
+    }
+
+    // Throws ArrayIndexOutOfBoundsException unless the index is in the array.
+    fn array_index(&mut self, arrayref: Item, index: i32) -> Result<usize, ExecError> {
+        let length = self.array(arrayref)?.elements.len();
+        if index < 0 || index as usize >= length {
+            let message = format!("Index {} out of bounds for length {}", index, length);
+            return Err(self.throw("java/lang/ArrayIndexOutOfBoundsException", Some(&message)));
+        }
+        Ok(index as usize)
+    }
+
+    fn return_value(&mut self, inst: &Instruction) -> Result<Item, ExecError> {
+        let stack = &mut self.get_current_mut_frame().operand_stack;
+        let val = match inst {
+            Instruction::Ireturn => Item::Int(stack.pop_int()?),
+            Instruction::Lreturn => Item::Long(stack.pop_long()?),
+            Instruction::Freturn => Item::Float(stack.pop_float()?),
+            Instruction::Dreturn => Item::Double(stack.pop_double()?),
+            _ => match stack.pop()? {
+                item @ (Item::Int(_) | Item::Long(_) | Item::Float(_) | Item::Double(_)) => {
+                    return Err(StackError::Type {
+                        expected: "reference",
+                        found: item,
+                    }
+                    .into())
+                }
+                item => item,
+            },
+        };
+        Ok(val)
+    }
+
+    // https://docs.oracle.com/javase/specs/jvms/se15/html/jvms-6.html#jvms-6.5.ldc2_w
+    // ldc and ldc_w load a category 1 constant, and ldc2_w loads a long or a double.
+    fn ldc(&mut self, index: u16, category2: bool) -> Result<(), ExecError> {
+        let class = self.current_class();
+        let val = self.constant(&class, index)?;
+        if val.is_category2() != category2 {
+            let inst = if category2 { "ldc2_w" } else { "ldc" };
+            return Err(format!("{} cannot load the constant pool #{}: {:?}", inst, index, val).into());
+        }
+        self.get_current_mut_frame().operand_stack.push(val)?;
+        Ok(())
+    }
+
+    // Reads a loadable constant for ldc or a ConstantValue attribute.
+    fn constant(&mut self, class: &Class, index: u16) -> Result<Item, ExecError> {
+        let constant_pool = class
+            .cp_info
+            .get(index)
+            .ok_or_else(|| format!("Constant pool #{} is not found", index))?;
+        let val = match constant_pool.info {
+            ConstantPoolInfo::StringInfo(string_info) => match &class.cp_info.get(string_info.bytes) {
+                Some(ConstantPool {
+                    info: ConstantPoolInfo::Utf8Info(utf8info),
+                    ..
+                }) => self.heap.intern(&utf8info.to_string()),
+                _ => return Err(format!("Constant pool #{} is not a Utf8", string_info.bytes).into()),
+            },
+            ConstantPoolInfo::IntegerInfo(integer_info) => Item::Int(integer_info.value()),
+            ConstantPoolInfo::FloatInfo(float_info) => Item::Float(float_info.value()),
+            ConstantPoolInfo::LongInfo(long_info) => Item::Long(long_info.value()),
+            ConstantPoolInfo::DoubleInfo(double_info) => Item::Double(double_info.value()),
+            _ => return Err(format!("Constant pool #{} is not a loadable constant", index).into()),
         };
+        Ok(val)
+    }
+
+    fn load(&mut self, index: usize) -> Result<(), ExecError> {
+        let frame = self.get_current_mut_frame();
+        let val = frame.local_variable.get(index)?;
+        frame.operand_stack.push(val)?;
+        Ok(())
+    }
+
+    fn store(&mut self, index: usize) -> Result<(), ExecError> {
+        let frame = self.get_current_mut_frame();
+        let val = frame.operand_stack.pop()?;
+        frame.local_variable.set(index, val)?;
+        Ok(())
+    }
+
+    fn binary_int(&mut self, op: impl Fn(i32, i32) -> i32) -> Result<(), ExecError> {
+        let stack = &mut self.get_current_mut_frame().operand_stack;
+        let v2 = stack.pop_int()?;
+        let v1 = stack.pop_int()?;
+        stack.push(Item::Int(op(v1, v2)))?;
+        Ok(())
+    }
+
+    fn binary_long(&mut self, op: impl Fn(i64, i64) -> i64) -> Result<(), ExecError> {
+        let stack = &mut self.get_current_mut_frame().operand_stack;
+        let v2 = stack.pop_long()?;
+        let v1 = stack.pop_long()?;
+        stack.push(Item::Long(op(v1, v2)))?;
+        Ok(())
+    }
+
+    // The shift distance of lshl, lshr and lushr is an int.
+    fn shift_long(&mut self, op: impl Fn(i64, i32) -> i64) -> Result<(), ExecError> {
+        let stack = &mut self.get_current_mut_frame().operand_stack;
+        let v2 = stack.pop_int()?;
+        let v1 = stack.pop_long()?;
+        stack.push(Item::Long(op(v1, v2)))?;
+        Ok(())
+    }
+
+    fn binary_float(&mut self, op: impl Fn(f32, f32) -> f32) -> Result<(), ExecError> {
+        let stack = &mut self.get_current_mut_frame().operand_stack;
+        let v2 = stack.pop_float()?;
+        let v1 = stack.pop_float()?;
+        stack.push(Item::Float(op(v1, v2)))?;
+        Ok(())
+    }
+
+    fn binary_double(&mut self, op: impl Fn(f64, f64) -> f64) -> Result<(), ExecError> {
+        let stack = &mut self.get_current_mut_frame().operand_stack;
+        let v2 = stack.pop_double()?;
+        let v1 = stack.pop_double()?;
+        stack.push(Item::Double(op(v1, v2)))?;
+        Ok(())
+    }
+
+    // Branch offsets are relative to the pc of the branch instruction itself.
+    fn jump(&mut self, pc: usize, offset: i32) {
+        self.get_current_mut_frame().pc = (pc as isize + offset as isize) as usize;
+    }
+
+    fn if_int(&mut self, pc: usize, offset: i16, cond: impl Fn(i32) -> bool) -> Result<(), ExecError> {
+        if cond(self.get_current_mut_frame().operand_stack.pop_int()?) {
+            self.jump(pc, offset.into());
+        }
+        Ok(())
+    }
+
+    fn if_icmp(&mut self, pc: usize, offset: i16, cond: impl Fn(i32, i32) -> bool) -> Result<(), ExecError> {
+        let stack = &mut self.get_current_mut_frame().operand_stack;
+        let v2 = stack.pop_int()?;
+        let v1 = stack.pop_int()?;
+        if cond(v1, v2) {
+            self.jump(pc, offset.into());
+        }
+        Ok(())
+    }
+
+    fn if_acmp(&mut self, pc: usize, offset: i16, cond: impl Fn(Item, Item) -> bool) -> Result<(), ExecError> {
+        let stack = &mut self.get_current_mut_frame().operand_stack;
+        let v2 = stack.pop()?;
+        let v1 = stack.pop()?;
+        if cond(v1, v2) {
+            self.jump(pc, offset.into());
+        }
+        Ok(())
+    }
+
+    fn if_ref(&mut self, pc: usize, offset: i16, cond: impl Fn(Item) -> bool) -> Result<(), ExecError> {
+        if cond(self.get_current_mut_frame().operand_stack.pop()?) {
+            self.jump(pc, offset.into());
+        }
         Ok(())
     }
 }
@@ -0,0 +1,221 @@
+mod floating_decimal;
+
+use std::io::Write;
+
+use super::VM;
+use crate::class::descriptor::{FieldType, MethodDescriptor};
+use crate::exception::{builtin_super_class, ExecError};
+use crate::operand_stack::{Item, StackError};
+
+// A method of a built-in class implemented in Rust. `args` starts with the receiver unless the
+// method is static, and the descriptor tells apart the overloads.
+pub type NativeMethod = fn(&mut VM, &MethodDescriptor, &[Item]) -> Result<Option<Item>, ExecError>;
+
+const NATIVE_METHODS: &[(&str, &str, &str, NativeMethod)] = &[
+    ("java/lang/Object", "<init>", "()V", object_init),
+    ("java/lang/Throwable", "<init>", "()V", throwable_init),
+    ("java/lang/Throwable", "<init>", "(Ljava/lang/String;)V", throwable_init),
+    (
+        "java/lang/Throwable",
+        "<init>",
+        "(Ljava/lang/String;Ljava/lang/Throwable;)V",
+        throwable_init,
+    ),
+    (
+        "java/lang/Throwable",
+        "<init>",
+        "(Ljava/lang/Throwable;)V",
+        throwable_init,
+    ),
+    (
+        "java/lang/Throwable",
+        "getMessage",
+        "()Ljava/lang/String;",
+        throwable_get_message,
+    ),
+    (
+        "java/lang/Throwable",
+        "getCause",
+        "()Ljava/lang/Throwable;",
+        throwable_get_cause,
+    ),
+    ("java/io/PrintStream", "print", "(Z)V", print_stream_print),
+    ("java/io/PrintStream", "print", "(C)V", print_stream_print),
+    ("java/io/PrintStream", "print", "(I)V", print_stream_print),
+    ("java/io/PrintStream", "print", "(J)V", print_stream_print),
+    ("java/io/PrintStream", "print", "(F)V", print_stream_print),
+    ("java/io/PrintStream", "print", "(D)V", print_stream_print),
+    (
+        "java/io/PrintStream",
+        "print",
+        "(Ljava/lang/String;)V",
+        print_stream_print,
+    ),
+    (
+        "java/io/PrintStream",
+        "print",
+        "(Ljava/lang/Object;)V",
+        print_stream_print,
+    ),
+    ("java/io/PrintStream", "println", "()V", print_stream_println),
+    ("java/io/PrintStream", "println", "(Z)V", print_stream_println),
+    ("java/io/PrintStream", "println", "(C)V", print_stream_println),
+    ("java/io/PrintStream", "println", "(I)V", print_stream_println),
+    ("java/io/PrintStream", "println", "(J)V", print_stream_println),
+    ("java/io/PrintStream", "println", "(F)V", print_stream_println),
+    ("java/io/PrintStream", "println", "(D)V", print_stream_println),
+    (
+        "java/io/PrintStream",
+        "println",
+        "(Ljava/lang/String;)V",
+        print_stream_println,
+    ),
+    (
+        "java/io/PrintStream",
+        "println",
+        "(Ljava/lang/Object;)V",
+        print_stream_println,
+    ),
+];
+
+// The methods of java.lang.Object, which is the only built-in class whose methods are all known.
+const OBJECT_METHODS: &[(&str, &str)] = &[
+    ("<init>", "()V"),
+    ("getClass", "()Ljava/lang/Class;"),
+    ("hashCode", "()I"),
+    ("equals", "(Ljava/lang/Object;)Z"),
+    ("clone", "()Ljava/lang/Object;"),
+    ("toString", "()Ljava/lang/String;"),
+    ("notify", "()V"),
+    ("notifyAll", "()V"),
+    ("wait", "()V"),
+    ("wait", "(J)V"),
+    ("wait", "(JI)V"),
+    ("finalize", "()V"),
+];
+
+pub fn find(class_name: &str, name: &str, descriptor: &str) -> Option<NativeMethod> {
+    // The built-in exceptions declare no members of their own, so their constructors are the ones of
+    // Throwable.
+    let class_name = match builtin_super_class(class_name) {
+        Some(_) => "java/lang/Throwable",
+        None => class_name,
+    };
+    NATIVE_METHODS
+        .iter()
+        .find(|(class, method, method_descriptor, _)| {
+            *class == class_name && *method == name && *method_descriptor == descriptor
+        })
+        .map(|(_, _, _, native)| *native)
+}
+
+// Whether the JDK may declare the method in the built-in class although rjvm does not implement it.
+// Only Object is known completely, so the other classes may declare any method.
+pub fn may_declare(class_name: &str, name: &str, descriptor: &str) -> bool {
+    class_name != "java/lang/Object" || OBJECT_METHODS.contains(&(name, descriptor))
+}
+
+fn object_init(_: &mut VM, _: &MethodDescriptor, _: &[Item]) -> Result<Option<Item>, ExecError> {
+    Ok(None)
+}
+
+// Throwable(), Throwable(String), Throwable(String, Throwable) and Throwable(Throwable). The last one
+// takes the message from the cause like `cause.toString()`.
+fn throwable_init(vm: &mut VM, descriptor: &MethodDescriptor, args: &[Item]) -> Result<Option<Item>, ExecError> {
+    let (message, cause) = match (descriptor.parameters.as_slice(), &args[1..]) {
+        ([], []) => (Item::Null, Item::Null),
+        ([FieldType::Object(class)], [message]) if class == "java/lang/String" => (*message, Item::Null),
+        ([_, _], [message, cause]) => (*message, *cause),
+        ([_], [Item::Objectref(index)]) => {
+            let cause = vm.heap.get(*index).ok_or("Invalid object reference")?;
+            let class_name = cause.class_name.replace('/', ".");
+            let message = match cause
+                .field("java/lang/Throwable", "detailMessage")
+                .map(|field| field.value)
+            {
+                Some(Item::String(message)) => {
+                    let message = vm.heap.string(message).ok_or("Invalid string reference")?;
+                    format!("{}: {}", class_name, message)
+                }
+                _ => class_name,
+            };
+            (vm.heap.intern(&message), Item::Objectref(*index))
+        }
+        ([_], [cause]) => (Item::Null, *cause),
+        _ => return Err(format!("Throwable constructor {} is not implemented yet", descriptor).into()),
+    };
+    let object = match args[0] {
+        Item::Objectref(index) => vm.heap.get_mut(index).ok_or("Invalid object reference")?,
+        item => {
+            return Err(StackError::Type {
+                expected: "object",
+                found: item,
+            }
+            .into())
+        }
+    };
+    for (name, value) in [("detailMessage", message), ("cause", cause)] {
+        if let Some(field) = object.field_mut("java/lang/Throwable", name) {
+            field.value = value;
+        }
+    }
+    Ok(None)
+}
+
+fn throwable_get_message(vm: &mut VM, _: &MethodDescriptor, args: &[Item]) -> Result<Option<Item>, ExecError> {
+    throwable_field(vm, args[0], "detailMessage")
+}
+
+fn throwable_get_cause(vm: &mut VM, _: &MethodDescriptor, args: &[Item]) -> Result<Option<Item>, ExecError> {
+    throwable_field(vm, args[0], "cause")
+}
+
+fn throwable_field(vm: &VM, receiver: Item, name: &str) -> Result<Option<Item>, ExecError> {
+    let value = match receiver {
+        Item::Objectref(index) => vm
+            .heap
+            .get(index)
+            .ok_or("Invalid object reference")?
+            .field("java/lang/Throwable", name)
+            .map(|field| field.value),
+        _ => None,
+    };
+    Ok(Some(value.unwrap_or(Item::Null)))
+}
+
+fn print_stream_print(vm: &mut VM, descriptor: &MethodDescriptor, args: &[Item]) -> Result<Option<Item>, ExecError> {
+    print!("{}", to_java_string(vm, descriptor, args)?);
+    std::io::stdout().flush().map_err(|err| err.to_string())?;
+    Ok(None)
+}
+
+fn print_stream_println(vm: &mut VM, descriptor: &MethodDescriptor, args: &[Item]) -> Result<Option<Item>, ExecError> {
+    println!("{}", to_java_string(vm, descriptor, args)?);
+    Ok(None)
+}
+
+// Formats the argument of print or println like String.valueOf.
+fn to_java_string(vm: &VM, descriptor: &MethodDescriptor, args: &[Item]) -> Result<String, ExecError> {
+    // boolean and char values are ints on the operand stack, so the descriptor decides how they
+    // are printed.
+    let string = match (descriptor.parameters.first(), args.get(1)) {
+        (None, _) => String::new(),
+        (Some(FieldType::Boolean), Some(Item::Int(val))) => (*val != 0).to_string(),
+        (Some(FieldType::Char), Some(Item::Int(val))) => String::from_utf16_lossy(&[*val as u16]),
+        (_, Some(Item::Null)) => "null".to_string(),
+        (_, Some(Item::Int(val))) => val.to_string(),
+        (_, Some(Item::Long(val))) => val.to_string(),
+        (_, Some(Item::Float(val))) => floating_decimal::float_to_string(*val),
+        (_, Some(Item::Double(val))) => floating_decimal::double_to_string(*val),
+        (_, Some(Item::String(index))) => vm.heap.string(*index).ok_or("Invalid string reference")?.to_string(),
+        (_, Some(item)) => {
+            return Err(StackError::Type {
+                expected: "printable value",
+                found: *item,
+            }
+            .into())
+        }
+        (Some(_), None) => return Err(StackError::Underflow.into()),
+    };
+    Ok(string)
+}
@@ -0,0 +1,438 @@
+use std::cmp::{self, Ordering};
+use std::fmt;
+use std::iter;
+
+// https://docs.oracle.com/en/java/javase/17/docs/api/java.base/java/lang/Double.html#toString(double)
+// Float.toString and Double.toString up to JDK 18 are jdk.internal.math.FloatingDecimal, and this is a
+// port of its conversion. Its digits are not always the shortest ones: 1.0E23 is printed as
+// 9.999999999999999E22 and 2.0E23 as 1.9999999999999998E23. In the scientific notation it develops a
+// second digit instead of padding with a zero, so Double.MIN_VALUE, the closest double to 5E-324,
+// is printed as 4.9E-324.
+
+const EXP_SHIFT: i32 = 52;
+const FRACT_HOB: u64 = 1 << EXP_SHIFT;
+const SIGNIF_BIT_MASK: u64 = FRACT_HOB - 1;
+const EXP_BIAS: i32 = 1023;
+const SINGLE_EXP_SHIFT: i32 = 23;
+const SINGLE_FRACT_HOB: u32 = 1 << SINGLE_EXP_SHIFT;
+const SINGLE_EXP_BIAS: i32 = 127;
+
+// The binary exponents of the values that are converted exactly as a long.
+const MAX_SMALL_BIN_EXP: i32 = 62;
+const MIN_SMALL_BIN_EXP: i32 = -(63 / 3);
+
+// The number of bits of 5^i.
+const N_5_BITS: [i32; 27] = [
+    0, 3, 5, 7, 10, 12, 14, 17, 19, 21, 24, 26, 28, 31, 33, 35, 38, 40, 42, 45, 47, 49, 52, 54, 56, 59, 61,
+];
+
+// The number of decimal digits of 2^i that are below its precision.
+const INSIGNIFICANT_DIGITS_NUMBER: [i32; 64] = [
+    0, 0, 0, 0, 1, 1, 1, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 5, 5, 5, 6, 6, 6, 6, 7, 7, 7, 8, 8, 8, 9, 9, 9, 9, 10, 10, 10,
+    11, 11, 11, 12, 12, 12, 12, 13, 13, 13, 14, 14, 14, 15, 15, 15, 15, 16, 16, 16, 17, 17, 17, 18, 18, 18, 19,
+];
+
+pub fn float_to_string(val: f32) -> String {
+    if val.is_nan() {
+        return "NaN".into();
+    }
+    let sign = if val.is_sign_negative() { "-" } else { "" };
+    let bits = val.to_bits();
+    let mut fract_bits = bits & (SINGLE_FRACT_HOB - 1);
+    let mut bin_exp = ((bits >> SINGLE_EXP_SHIFT) & 0xff) as i32;
+    let n_significant_bits = if bin_exp == 0xff {
+        return format!("{}Infinity", sign);
+    } else if bin_exp == 0 {
+        if fract_bits == 0 {
+            return format!("{}0.0", sign);
+        }
+        // A subnormal value is normalized.
+        let leading_zeros = fract_bits.leading_zeros() as i32;
+        let shift = leading_zeros - (31 - SINGLE_EXP_SHIFT);
+        fract_bits <<= shift;
+        bin_exp = 1 - shift;
+        32 - leading_zeros
+    } else {
+        fract_bits |= SINGLE_FRACT_HOB;
+        SINGLE_EXP_SHIFT + 1
+    };
+    bin_exp -= SINGLE_EXP_BIAS;
+    let fract_bits = u64::from(fract_bits) << (EXP_SHIFT - SINGLE_EXP_SHIFT);
+    format!("{}{}", sign, dtoa(bin_exp, fract_bits, n_significant_bits))
+}
+
+pub fn double_to_string(val: f64) -> String {
+    if val.is_nan() {
+        return "NaN".into();
+    }
+    let sign = if val.is_sign_negative() { "-" } else { "" };
+    let bits = val.to_bits();
+    let mut fract_bits = bits & SIGNIF_BIT_MASK;
+    let mut bin_exp = ((bits >> EXP_SHIFT) & 0x7ff) as i32;
+    let n_significant_bits = if bin_exp == 0x7ff {
+        return format!("{}Infinity", sign);
+    } else if bin_exp == 0 {
+        if fract_bits == 0 {
+            return format!("{}0.0", sign);
+        }
+        let leading_zeros = fract_bits.leading_zeros() as i32;
+        let shift = leading_zeros - (63 - EXP_SHIFT);
+        fract_bits <<= shift;
+        bin_exp = 1 - shift;
+        64 - leading_zeros
+    } else {
+        fract_bits |= FRACT_HOB;
+        EXP_SHIFT + 1
+    };
+    bin_exp -= EXP_BIAS;
+    format!("{}{}", sign, dtoa(bin_exp, fract_bits, n_significant_bits))
+}
+
+// The decimal digits of a positive value, which is 0.d1d2... * 10^dec_exponent.
+struct Decimal {
+    digits: Vec<u8>,
+    dec_exponent: i32,
+}
+
+impl Decimal {
+    fn round_up(&mut self) {
+        let mut i = self.digits.len() - 1;
+        while self.digits[i] == b'9' && i > 0 {
+            self.digits[i] = b'0';
+            i -= 1;
+        }
+        if self.digits[i] == b'9' {
+            // Carried out of the first digit, which leaves 10 followed by the zeros.
+            self.dec_exponent += 1;
+            self.digits[0] = b'1';
+        } else {
+            self.digits[i] += 1;
+        }
+    }
+}
+
+// Plain decimal notation for magnitudes in [10^-3, 10^7) and computerized scientific notation
+// otherwise, always with at least one fractional digit.
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = String::from_utf8_lossy(&self.digits);
+        let n_digits = digits.len() as i32;
+        if self.dec_exponent > 0 && self.dec_exponent < 8 {
+            let int_len = cmp::min(n_digits, self.dec_exponent) as usize;
+            let (int_part, frac_part) = digits.split_at(int_len);
+            write!(f, "{}", int_part)?;
+            if (int_len as i32) < self.dec_exponent {
+                write!(f, "{}.0", "0".repeat(self.dec_exponent as usize - int_len))?;
+            } else {
+                write!(f, ".{}", if frac_part.is_empty() { "0" } else { frac_part })?;
+            }
+        } else if self.dec_exponent <= 0 && self.dec_exponent > -3 {
+            write!(f, "0.{}{}", "0".repeat(-self.dec_exponent as usize), digits)?;
+        } else {
+            let (first, rest) = digits.split_at(1);
+            write!(
+                f,
+                "{}.{}E{}",
+                first,
+                if rest.is_empty() { "0" } else { rest },
+                self.dec_exponent - 1
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// Converts `fract_bits * 2^(bin_exp - EXP_SHIFT)`, where the highest bit of `fract_bits` is the one
+// of FRACT_HOB and `n_significant_bits` bits below and including it are significant.
+fn dtoa(bin_exp: i32, fract_bits: u64, n_significant_bits: i32) -> Decimal {
+    let tail_zeros = fract_bits.trailing_zeros() as i32;
+    // The number of significant bits to the right of the binary point.
+    let n_fract_bits = EXP_SHIFT + 1 - tail_zeros;
+    let n_tiny_bits = cmp::max(0, n_fract_bits - bin_exp - 1);
+    // An integer that fits in a long has its digits developed directly.
+    if (MIN_SMALL_BIN_EXP..=MAX_SMALL_BIN_EXP).contains(&bin_exp) && n_tiny_bits == 0 {
+        let insignificant = if bin_exp > n_significant_bits {
+            insignificant_digits_for_pow2(bin_exp - n_significant_bits - 1)
+        } else {
+            0
+        };
+        let value = if bin_exp >= EXP_SHIFT {
+            fract_bits << (bin_exp - EXP_SHIFT)
+        } else {
+            fract_bits >> (EXP_SHIFT - bin_exp)
+        };
+        return develop_long_digits(value, insignificant);
+    }
+
+    // Computes integers B, S and M with val = B / S * 10^dec_exp and 1 <= B / S < 10, where M is half
+    // the ULP of val scaled like B. The digits are the quotients of B / S, and they stop once the
+    // remainder is within M of either neighbour. B5, S5 and M5 are the powers of 5 and B2, S2 and M2
+    // the powers of 2 of them.
+    let mut dec_exp = estimate_dec_exp(fract_bits, bin_exp);
+    let b5 = cmp::max(0, -dec_exp);
+    let mut b2 = b5 + n_tiny_bits + bin_exp;
+    let s5 = cmp::max(0, dec_exp);
+    let mut s2 = s5 + n_tiny_bits;
+    let m5 = b5;
+    let mut m2 = b2 - n_significant_bits;
+
+    let fract_bits = fract_bits >> tail_zeros;
+    b2 -= n_fract_bits - 1;
+    let common2factor = cmp::min(b2, s2);
+    b2 -= common2factor;
+    s2 -= common2factor;
+    m2 -= common2factor;
+    // The next smaller value of an exact power of two is only half as far away.
+    if n_fract_bits == 1 {
+        m2 -= 1;
+    }
+    if m2 < 0 {
+        b2 -= m2;
+        s2 -= m2;
+        m2 = 0;
+    }
+
+    // The digits are developed in ints or longs if B and 10 * S fit, where the arithmetic wraps like
+    // Java's does.
+    let b_bits = n_fract_bits + b2 + N_5_BITS.get(b5 as usize).copied().unwrap_or(b5 * 3);
+    let ten_s_bits = s2 + 1 + N_5_BITS.get(s5 as usize + 1).copied().unwrap_or((s5 + 1) * 3);
+    let mut digits = vec![];
+    let (low, high, low_digit_difference) = if b_bits < 64 && ten_s_bits < 64 {
+        let is_int = b_bits < 32 && ten_s_bits < 32;
+        let wrap = |val: i64| if is_int { i64::from(val as i32) } else { val };
+        let mut b = wrap(((fract_bits as i64) * 5i64.pow(b5 as u32)) << b2);
+        let s = wrap(5i64.pow(s5 as u32) << s2);
+        let mut m = wrap(5i64.pow(m5 as u32) << m2);
+        let tens = wrap(s * 10);
+
+        // If the estimated dec_exp is one too large, the first quotient is zero and discarded.
+        let q = b / s;
+        b = wrap(10 * (b % s));
+        m = wrap(m.wrapping_mul(10));
+        let mut low = b < m;
+        let mut high = wrap(b.wrapping_add(m)) > tens;
+        if q == 0 && !high {
+            dec_exp -= 1;
+        } else {
+            digits.push(b'0' + q as u8);
+        }
+        // The scientific notation needs a digit after the point, so a second one is developed.
+        if !(-3..8).contains(&dec_exp) {
+            low = false;
+            high = false;
+        }
+        while !low && !high {
+            let q = b / s;
+            b = wrap(10 * (b % s));
+            m = wrap(m.wrapping_mul(10));
+            if m > 0 {
+                low = b < m;
+                high = wrap(b.wrapping_add(m)) > tens;
+            } else {
+                // M overflowed, so it is certainly larger than B.
+                low = true;
+                high = true;
+            }
+            digits.push(b'0' + q as u8);
+        }
+        (low, high, wrap(wrap(b << 1).wrapping_sub(tens)).cmp(&0))
+    } else {
+        let s = BigInt::new(1, s5, s2);
+        let mut b = BigInt::new(fract_bits, b5, b2);
+        // M and 10 * S are scaled by 10 like B is by the quotient of each digit.
+        let mut m = BigInt::new(1, m5 + 1, m2 + 1);
+        let ten_s = BigInt::new(1, s5 + 1, s2 + 1);
+
+        let q = b.quo_rem_iteration(&s);
+        let mut low = b < m;
+        let mut high = b.add(&m) >= ten_s;
+        if q == 0 && !high {
+            dec_exp -= 1;
+        } else {
+            digits.push(b'0' + q as u8);
+        }
+        if !(-3..8).contains(&dec_exp) {
+            low = false;
+            high = false;
+        }
+        while !low && !high {
+            let q = b.quo_rem_iteration(&s);
+            m.mul_small(10);
+            low = b < m;
+            high = b.add(&m) >= ten_s;
+            digits.push(b'0' + q as u8);
+        }
+        let low_digit_difference = if high && low {
+            b.shl(1);
+            b.cmp(&ten_s)
+        } else {
+            Ordering::Equal
+        };
+        (low, high, low_digit_difference)
+    };
+
+    let mut decimal = Decimal {
+        digits,
+        dec_exponent: dec_exp + 1,
+    };
+    // The last digit is rounded to the nearer one of the neighbours, and to even on a tie.
+    if high {
+        let round_up = match (low, low_digit_difference) {
+            (false, _) => true,
+            (true, Ordering::Equal) => decimal.digits.last().is_some_and(|digit| digit & 1 != 0),
+            (true, ord) => ord == Ordering::Greater,
+        };
+        if round_up {
+            decimal.round_up();
+        }
+    }
+    decimal
+}
+
+fn insignificant_digits_for_pow2(p2: i32) -> i32 {
+    if p2 > 1 {
+        INSIGNIFICANT_DIGITS_NUMBER.get(p2 as usize).copied().unwrap_or(0)
+    } else {
+        0
+    }
+}
+
+// Drops the insignificant digits with rounding and the trailing zeros.
+fn develop_long_digits(mut value: u64, insignificant: i32) -> Decimal {
+    let mut dec_exponent = 0;
+    if insignificant != 0 {
+        let pow10 = 10u64.pow(insignificant as u32);
+        let residue = value % pow10;
+        value /= pow10;
+        dec_exponent += insignificant;
+        if residue >= pow10 / 2 {
+            value += 1;
+        }
+    }
+    let digits = value.to_string();
+    Decimal {
+        digits: digits.trim_end_matches('0').as_bytes().to_vec(),
+        dec_exponent: dec_exponent + digits.len() as i32,
+    }
+}
+
+// floor(log10(val)) estimated from the binary exponent and the first bits of the fraction, which
+// can be one too large. The constant is Java's approximation of log10(2), not LOG10_2.
+#[allow(clippy::approx_constant)]
+fn estimate_dec_exp(fract_bits: u64, bin_exp: i32) -> i32 {
+    let d2 = f64::from_bits(((EXP_BIAS as u64) << EXP_SHIFT) | (fract_bits & SIGNIF_BIT_MASK));
+    let d = (d2 - 1.5) * 0.289529654 + 0.176091259 + f64::from(bin_exp) * 0.301029995663981;
+    d.floor() as i32
+}
+
+// An unsigned integer of any size with 32-bit limbs from the least significant one and no leading
+// zero limbs.
+#[derive(PartialEq, Eq)]
+struct BigInt {
+    limbs: Vec<u32>,
+}
+
+impl BigInt {
+    // val * 5^p5 * 2^p2
+    fn new(val: u64, p5: i32, p2: i32) -> Self {
+        let mut big = Self {
+            limbs: vec![val as u32, (val >> 32) as u32],
+        };
+        big.trim();
+        for _ in 0..p5 {
+            big.mul_small(5);
+        }
+        big.shl(p2 as u32);
+        big
+    }
+
+    fn trim(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    fn mul_small(&mut self, n: u32) {
+        let mut carry = 0;
+        for limb in &mut self.limbs {
+            let val = u64::from(*limb) * u64::from(n) + carry;
+            *limb = val as u32;
+            carry = val >> 32;
+        }
+        if carry != 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    fn shl(&mut self, bits: u32) {
+        if self.limbs.is_empty() {
+            return;
+        }
+        let shift = bits % 32;
+        if shift != 0 {
+            let mut carry = 0;
+            for limb in &mut self.limbs {
+                let val = (*limb << shift) | carry;
+                carry = *limb >> (32 - shift);
+                *limb = val;
+            }
+            if carry != 0 {
+                self.limbs.push(carry);
+            }
+        }
+        self.limbs.splice(0..0, iter::repeat_n(0, (bits / 32) as usize));
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let mut limbs = vec![];
+        let mut carry = 0;
+        for i in 0..cmp::max(self.limbs.len(), other.limbs.len()) {
+            let val = u64::from(self.limbs.get(i).copied().unwrap_or(0))
+                + u64::from(other.limbs.get(i).copied().unwrap_or(0))
+                + carry;
+            limbs.push(val as u32);
+            carry = val >> 32;
+        }
+        if carry != 0 {
+            limbs.push(carry as u32);
+        }
+        Self { limbs }
+    }
+
+    // Requires self >= other.
+    fn sub(&mut self, other: &Self) {
+        let mut borrow = 0;
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let val = i64::from(*limb) - i64::from(other.limbs.get(i).copied().unwrap_or(0)) - borrow;
+            *limb = val as u32;
+            borrow = if val < 0 { 1 } else { 0 };
+        }
+        self.trim();
+    }
+
+    // Returns self / s, which is a digit, and replaces self with the remainder times 10.
+    fn quo_rem_iteration(&mut self, s: &Self) -> u32 {
+        let mut q = 0;
+        while *self >= *s {
+            self.sub(s);
+            q += 1;
+        }
+        self.mul_small(10);
+        q
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -2,25 +2,58 @@
 ESC=$(printf '\033')
 EXPECTED_OUTPUT_FILE=".expected_output_for_test"
 ACTUAL_OUTPUT_FILE=".actual_output_for_test"
+# Every sample gets the same command-line arguments.
+SAMPLE_ARGS=(first -v "third arg")
+# rjvm always writes UTF-8, while java encodes its output with the locale.
+export LC_ALL=C.UTF-8
 
 clean() {
     rm $EXPECTED_OUTPUT_FILE $ACTUAL_OUTPUT_FILE
 }
 
-for class_file in samples/*.class; do
-    printf "${ESC}[32m%s${ESC}[m%s\n" 'Running: ' "${class_file}"
-    class_name=$(basename -- "$class_file" | cut -d '.' -f 1)
-    (cd samples && java "$class_name" > "../$EXPECTED_OUTPUT_FILE")
-    if cargo run "$class_file" > $ACTUAL_OUTPUT_FILE; then
+# Compares the output of rjvm run with rjvm_args and the one of java. The arguments are the sample
+# and the arguments of java, which runs in samples/.
+run_test() {
+    sample=$1
+    shift
+    printf "${ESC}[32m%s${ESC}[m%s\n" 'Running: ' "${sample}"
+    (cd samples && java "$@" > "../$EXPECTED_OUTPUT_FILE")
+    if cargo run -- "${rjvm_args[@]}" > $ACTUAL_OUTPUT_FILE; then
         if diff -q $EXPECTED_OUTPUT_FILE $ACTUAL_OUTPUT_FILE >/dev/null ; then
-            printf "${ESC}[32m%s${ESC}[m\n\n" "Test ${class_file} successful."
+            printf "${ESC}[32m%s${ESC}[m\n\n" "Test ${sample} successful."
         else
-            printf "${ESC}[31m%s${ESC}[m\n" "Error: ${class_file}"
+            printf "${ESC}[31m%s${ESC}[m\n" "Error: ${sample}"
             clean
             exit 1
         fi
-    else 
-        printf "${ESC}[31m%s${ESC}[m\n" "Error: ${class_file}"
+    else
+        printf "${ESC}[31m%s${ESC}[m\n" "Error: ${sample}"
+        clean
+        exit 1
+    fi
+}
+
+for class_file in samples/*.class; do
+    class_name=$(basename -- "$class_file" | cut -d '.' -f 1)
+    rjvm_args=(-cp samples "$class_name" "${SAMPLE_ARGS[@]}")
+    run_test "$class_file" "$class_name" "${SAMPLE_ARGS[@]}"
+done
+
+for jar_file in samples/*.jar; do
+    rjvm_args=(-jar "$jar_file" "${SAMPLE_ARGS[@]}")
+    run_test "$jar_file" -jar "$(basename -- "$jar_file")" "${SAMPLE_ARGS[@]}"
+done
+
+# The class dumps of -v are compared with the expected ones next to the classes, which are compiled
+# for a release new enough to have invokedynamic and modules in their constant pools.
+for expected_dump in samples/dump/*.txt; do
+    class_name=$(basename -- "$expected_dump" .txt)
+    printf "${ESC}[32m%s${ESC}[m%s\n" 'Dumping: ' "${class_name}"
+    if cargo run -- -v -cp samples/dump "$class_name" > $ACTUAL_OUTPUT_FILE \
+        && diff -q "$expected_dump" $ACTUAL_OUTPUT_FILE >/dev/null ; then
+        printf "${ESC}[32m%s${ESC}[m\n\n" "Test ${expected_dump} successful."
+    else
+        printf "${ESC}[31m%s${ESC}[m\n" "Error: ${expected_dump}"
         clean
         exit 1
     fi